@@ -1,9 +1,9 @@
 use crate::api::session::{Authorized, MlbSession};
-use crate::api::stats::schedule;
 use crate::data::teamdata::Team;
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
 use serde::Deserialize;
+use std::fmt;
 use std::str::FromStr;
 
 const MEDIA_GATEWAY_URL: &str = "https://media-gateway.mlb.com/graphql";
@@ -31,9 +31,9 @@ pub struct ContentSearchResults {
 #[serde(rename_all = "camelCase")]
 pub struct StreamData {
     pub media_id: String,
-    feed_type: FeedType,
-    language: String,
-    media_state: MediaState,
+    pub feed_type: FeedType,
+    pub language: String,
+    pub media_state: MediaState,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
@@ -57,11 +57,21 @@ impl FromStr for FeedType {
     }
 }
 
+impl fmt::Display for FeedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeedType::Home => write!(f, "home"),
+            FeedType::Away => write!(f, "away"),
+            FeedType::Network => write!(f, "national"),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MediaState {
-    state: String,
-    media_type: MediaType,
+    pub state: String,
+    pub media_type: MediaType,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
@@ -245,6 +255,8 @@ impl MlbSession<Authorized> {
         Ok(res_body.data.init_playback_session)
     }
 
+    /// The stream's `game_pk`, feed and URL. The feed is the one found, which may be a
+    /// fallback, e.g. `national` or `home-audio`.
     pub async fn find_stream_playback_url(
         &self,
         team: &Team,
@@ -252,19 +264,11 @@ impl MlbSession<Authorized> {
         media_type: MediaType,
         feed_type: Option<FeedType>,
         game_number: Option<u8>,
-    ) -> Result<Option<String>> {
-        // Fetch schedule and filter for team games on specified date.
-        let Some(team_games) = self
-            .fetch_schedule_by_date(&date, None)
-            .await?
-            .and_then(|s| s.find_team_games(team))
-        else {
-            println!("No games found for the {} on {}", team.name, date);
+    ) -> Result<Option<(u64, String, String)>> {
+        // Find the team's game on specified date, then match feed type to team's home/away status if not provided.
+        let Some(game_data) = self.find_team_game(team, date, game_number, None).await? else {
             return Ok(None);
         };
-
-        // Return a single game then match feed type to team's home/away status if not provided.
-        let game_data = schedule::select_game(team_games, game_number)?;
         let feed_type = feed_type.unwrap_or_else(|| {
             if game_data.teams.home.team.name == team.name {
                 FeedType::Home
@@ -283,7 +287,15 @@ impl MlbSession<Authorized> {
         // Initialize a playback session containing stream URL.
         let playback_session = self.init_playback_session(&stream_data.media_id).await?;
 
-        Ok(Some(playback_session.playback.url))
+        let feed = match stream_data.media_state.media_type {
+            MediaType::Video => stream_data.feed_type.to_string(),
+            MediaType::Audio => format!("{}-audio", stream_data.feed_type),
+        };
+        Ok(Some((
+            game_data.game_pk,
+            feed,
+            playback_session.playback.url,
+        )))
     }
 }
 
@@ -437,4 +449,14 @@ mod tests {
         assert_eq!(FeedType::from_str("national").unwrap(), FeedType::Network);
         assert!(FeedType::from_str("invalid").is_err());
     }
+
+    #[test]
+    fn feed_type_displays_as_it_parses() {
+        for feed_type in [FeedType::Home, FeedType::Away, FeedType::Network] {
+            assert_eq!(
+                FeedType::from_str(&feed_type.to_string()).unwrap(),
+                feed_type
+            );
+        }
+    }
 }
@@ -0,0 +1,142 @@
+use crate::api::session::MlbSession;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveFeedResponse {
+    live_data: LiveData,
+}
+
+#[derive(Debug, Deserialize)]
+struct LiveData {
+    plays: Plays,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plays {
+    pub all_plays: Vec<Play>,
+    pub scoring_plays: Vec<usize>, // Indices into all_plays.
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Play {
+    pub result: PlayResult,
+    pub about: PlayAbout,
+    #[serde(default)]
+    pub play_events: Vec<PlayEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayResult {
+    pub description: Option<String>, // Missing for the at-bat currently in progress.
+    pub away_score: Option<u8>,
+    pub home_score: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayAbout {
+    pub inning: u8,
+    pub is_top_inning: bool,
+    #[serde(default)]
+    pub is_scoring_play: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayEvent {
+    #[serde(default)]
+    pub is_pitch: bool,
+    pub details: EventDetails,
+    pub count: Option<Count>,
+    pub pitch_data: Option<PitchData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventDetails {
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<PitchType>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PitchType {
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Count {
+    pub balls: u8,
+    pub strikes: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchData {
+    pub start_speed: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayFilter {
+    Scoring,
+    All,
+}
+
+impl FromStr for PlayFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "scoring" => Ok(Self::Scoring),
+            "all" => Ok(Self::All),
+            _ => anyhow::bail!("Invalid play filter: {s}; expected 'scoring' or 'all'"),
+        }
+    }
+}
+
+impl Plays {
+    /// Completed plays matching the filter, in game order.
+    pub fn filtered(&self, filter: PlayFilter) -> Vec<&Play> {
+        let plays: Vec<&Play> = match filter {
+            PlayFilter::All => self.all_plays.iter().collect(),
+            PlayFilter::Scoring => self
+                .scoring_plays
+                .iter()
+                .filter_map(|&idx| self.all_plays.get(idx))
+                .collect(),
+        };
+
+        plays
+            .into_iter()
+            .filter(|play| play.result.description.is_some())
+            .collect()
+    }
+}
+
+impl<State> MlbSession<State> {
+    pub async fn fetch_plays(&self, game_pk: u64) -> Result<Plays> {
+        let url = format!("https://statsapi.mlb.com/api/v1.1/game/{game_pk}/feed/live");
+
+        let res = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context("Failed to send live feed request")?
+            .error_for_status()
+            .context("Live feed fetch returned unsuccessful status")?;
+
+        let body: LiveFeedResponse = res
+            .json()
+            .await
+            .context("Failed to parse live feed response")?;
+
+        Ok(body.live_data.plays)
+    }
+}
@@ -1 +1,6 @@
+pub mod feed;
+pub mod people;
+pub mod roster;
 pub mod schedule;
+pub mod seasons;
+pub mod standings;
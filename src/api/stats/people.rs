@@ -0,0 +1,369 @@
+use crate::api::session::MlbSession;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+const STATS_API_URL: &str = "https://statsapi.mlb.com/api/v1";
+
+#[derive(Debug, Deserialize)]
+struct PeopleResponse {
+    people: Vec<Person>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: u32,
+    pub full_name: String,
+    pub primary_number: Option<String>,
+    pub birth_date: Option<String>,
+    pub current_age: Option<u8>,
+    pub birth_city: Option<String>,
+    pub birth_country: Option<String>,
+    pub height: Option<String>,
+    pub weight: Option<u16>,
+    #[serde(default)]
+    pub active: bool,
+    pub current_team: Option<PersonTeam>,
+    pub primary_position: Option<Position>,
+    pub mlb_debut_date: Option<String>,
+    pub bat_side: Option<Handedness>,
+    pub pitch_hand: Option<Handedness>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PersonTeam {
+    pub id: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Position {
+    pub code: String,
+    pub abbreviation: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Handedness {
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatsResponse {
+    stats: Vec<StatGroupResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatGroupResult {
+    #[serde(rename = "type")]
+    kind: StatLabel,
+    group: StatLabel,
+    pub splits: Vec<StatSplit>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatLabel {
+    display_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatSplit {
+    pub season: Option<String>,
+    pub date: Option<String>,
+    pub is_home: Option<bool>,
+    pub team: Option<PersonTeam>,
+    pub opponent: Option<PersonTeam>,
+    pub split: Option<SplitLabel>,
+    pub stat: StatLine,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SplitLabel {
+    pub description: String,
+}
+
+// Hitting and pitching lines share most keys; fields absent from a group stay None.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatLine {
+    pub games_played: Option<u32>,
+    pub games_started: Option<u32>,
+    pub at_bats: Option<u32>,
+    pub runs: Option<u32>,
+    pub hits: Option<u32>,
+    pub home_runs: Option<u32>,
+    pub rbi: Option<u32>,
+    pub stolen_bases: Option<u32>,
+    pub base_on_balls: Option<u32>,
+    pub strike_outs: Option<u32>,
+    pub avg: Option<String>,
+    pub obp: Option<String>,
+    pub slg: Option<String>,
+    pub ops: Option<String>,
+    pub wins: Option<u32>,
+    pub losses: Option<u32>,
+    pub saves: Option<u32>,
+    pub innings_pitched: Option<String>,
+    pub earned_runs: Option<u32>,
+    pub era: Option<String>,
+    pub whip: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatGroup {
+    Hitting,
+    Pitching,
+}
+
+impl StatGroup {
+    fn as_api_str(self) -> &'static str {
+        match self {
+            StatGroup::Hitting => "hitting",
+            StatGroup::Pitching => "pitching",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PlayerStats {
+    pub group: StatGroup,
+    pub season: Option<StatSplit>,
+    pub career: Option<StatSplit>,
+    pub game_log: Vec<StatSplit>,
+    pub splits: Vec<StatSplit>,
+}
+
+impl Person {
+    /// Pitchers get pitching lines, two-way players get both, everyone else hitting.
+    pub fn stat_groups(&self) -> Vec<StatGroup> {
+        match self.primary_position.as_ref().map(|p| p.code.as_str()) {
+            Some("1") => vec![StatGroup::Pitching],
+            Some("Y") => vec![StatGroup::Hitting, StatGroup::Pitching],
+            _ => vec![StatGroup::Hitting],
+        }
+    }
+}
+
+// Lowercase and strip accents/punctuation so "jose ramirez" matches "José Ramírez".
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter_map(|c| match c {
+            'á' | 'à' | 'ä' | 'â' | 'Á' => Some('a'),
+            'é' | 'è' | 'ë' | 'ê' | 'É' => Some('e'),
+            'í' | 'ì' | 'ï' | 'î' | 'Í' => Some('i'),
+            'ó' | 'ò' | 'ö' | 'ô' | 'Ó' => Some('o'),
+            'ú' | 'ù' | 'ü' | 'û' | 'Ú' => Some('u'),
+            'ñ' | 'Ñ' => Some('n'),
+            '.' | '\'' => None,
+            c => Some(c.to_ascii_lowercase()),
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Pick the search result whose name best matches the query, preferring active players on ties.
+fn best_match(query: &str, mut people: Vec<Person>) -> Option<Person> {
+    let query = normalize_name(query);
+    let score = |p: &Person| strsim::jaro_winkler(&query, &normalize_name(&p.full_name));
+
+    let pos = people
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| score(a).total_cmp(&score(b)).then(a.active.cmp(&b.active)))
+        .map(|(idx, _)| idx)?;
+
+    Some(people.swap_remove(pos))
+}
+
+impl<State> MlbSession<State> {
+    async fn search_people(&self, name: &str) -> Result<Vec<Person>> {
+        let res = self
+            .client
+            .get(format!("{STATS_API_URL}/people/search"))
+            .query(&[("names", name), ("sportIds", "1")])
+            .send()
+            .await
+            .context("Failed to send player search request")?
+            .error_for_status()
+            .context("Player search returned unsuccessful status")?;
+
+        let body: PeopleResponse = res
+            .json()
+            .await
+            .context("Failed to parse player search response")?;
+
+        Ok(body.people)
+    }
+
+    async fn fetch_person(&self, person_id: u32) -> Result<Person> {
+        let res = self
+            .client
+            .get(format!("{STATS_API_URL}/people/{person_id}"))
+            .query(&[("hydrate", "currentTeam")])
+            .send()
+            .await
+            .context("Failed to send player request")?
+            .error_for_status()
+            .context("Player fetch returned unsuccessful status")?;
+
+        let mut body: PeopleResponse = res
+            .json()
+            .await
+            .context("Failed to parse player response")?;
+
+        body.people
+            .pop()
+            .ok_or_else(|| anyhow::anyhow!("No player found with id {person_id}"))
+    }
+
+    /// Search for a player by name, falling back to a last-name search for misspelled first names.
+    pub async fn find_player(&self, name: &str) -> Result<Option<Person>> {
+        let mut people = self.search_people(name).await?;
+
+        if people.is_empty()
+            && let Some(last_name) = name.split_whitespace().last()
+            && last_name != name.trim()
+        {
+            tracing::debug!("No results for '{name}'; retrying with '{last_name}'");
+            people = self.search_people(last_name).await?;
+        }
+
+        match best_match(name, people) {
+            Some(person) => Ok(Some(self.fetch_person(person.id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn fetch_player_stats(
+        &self,
+        person_id: u32,
+        group: StatGroup,
+        season: i32,
+        game_log_limit: usize,
+    ) -> Result<PlayerStats> {
+        let season = season.to_string();
+        let res = self
+            .client
+            .get(format!("{STATS_API_URL}/people/{person_id}/stats"))
+            .query(&[
+                ("stats", "season,career,gameLog,statSplits"),
+                ("group", group.as_api_str()),
+                ("season", season.as_str()),
+                ("sitCodes", "vl,vr,h,a"),
+            ])
+            .send()
+            .await
+            .context("Failed to send player stats request")?
+            .error_for_status()
+            .context("Player stats fetch returned unsuccessful status")?;
+
+        let body: StatsResponse = res
+            .json()
+            .await
+            .context("Failed to parse player stats response")?;
+
+        let mut stats = PlayerStats {
+            group,
+            season: None,
+            career: None,
+            game_log: Vec::new(),
+            splits: Vec::new(),
+        };
+
+        for result in body.stats {
+            if result.group.display_name != group.as_api_str() {
+                continue;
+            }
+            match result.kind.display_name.as_str() {
+                "season" => stats.season = combined_split(result.splits),
+                "career" => stats.career = combined_split(result.splits),
+                "gameLog" => {
+                    // Game logs come back oldest first; keep the most recent N, newest first.
+                    stats.game_log = result.splits;
+                    stats.game_log.reverse();
+                    stats.game_log.truncate(game_log_limit);
+                }
+                "statSplits" => stats.splits = result.splits,
+                other => tracing::debug!("Ignoring unexpected stat type: {other}"),
+            }
+        }
+
+        Ok(stats)
+    }
+}
+
+// Players traded mid-season get one split per team plus a combined line without a team.
+fn combined_split(mut splits: Vec<StatSplit>) -> Option<StatSplit> {
+    match splits.iter().position(|s| s.team.is_none()) {
+        Some(pos) => Some(splits.swap_remove(pos)),
+        None => splits.into_iter().next(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_person(id: u32, name: &str, active: bool) -> Person {
+        Person {
+            id,
+            full_name: name.to_string(),
+            primary_number: None,
+            birth_date: None,
+            current_age: None,
+            birth_city: None,
+            birth_country: None,
+            height: None,
+            weight: None,
+            active,
+            current_team: None,
+            primary_position: None,
+            mlb_debut_date: None,
+            bat_side: None,
+            pitch_hand: None,
+        }
+    }
+
+    #[test]
+    fn normalize_name_strips_accents_and_punctuation() {
+        assert_eq!(normalize_name("José  Ramírez"), "jose ramirez");
+        assert_eq!(normalize_name("J.D. Martinez"), "jd martinez");
+        assert_eq!(normalize_name("Travis d'Arnaud"), "travis darnaud");
+    }
+
+    #[test]
+    fn best_match_prefers_closest_name() {
+        let people = vec![
+            mock_person(1, "Juan Soto", true),
+            mock_person(2, "Juan Sosa", true),
+        ];
+        assert_eq!(best_match("juan soto", people).unwrap().id, 1);
+    }
+
+    #[test]
+    fn best_match_tolerates_typos() {
+        let people = vec![
+            mock_person(1, "Freddie Freeman", true),
+            mock_person(2, "Mike Freeman", false),
+        ];
+        assert_eq!(best_match("fredie freman", people).unwrap().id, 1);
+    }
+
+    #[test]
+    fn best_match_prefers_active_players_on_ties() {
+        let people = vec![
+            mock_person(1, "Will Smith", false),
+            mock_person(2, "Will Smith", true),
+        ];
+        assert_eq!(best_match("Will Smith", people).unwrap().id, 2);
+    }
+
+    #[test]
+    fn best_match_returns_none_for_empty_results() {
+        assert!(best_match("nobody", Vec::new()).is_none());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::api::session::MlbSession;
+use crate::api::stats::people::{Person, Position};
+use crate::data::teamdata::Team;
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+struct RosterResponse {
+    roster: Vec<RosterEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub person: Person,
+    pub jersey_number: Option<String>,
+    pub position: Position,
+    pub status: RosterStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RosterStatus {
+    pub code: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterType {
+    Active,
+    FortyMan,
+    DepthChart,
+}
+
+impl RosterType {
+    fn as_api_str(self) -> &'static str {
+        match self {
+            RosterType::Active => "active",
+            RosterType::FortyMan => "40Man",
+            RosterType::DepthChart => "depthChart",
+        }
+    }
+}
+
+impl FromStr for RosterType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(Self::Active),
+            "40man" | "40-man" | "40" => Ok(Self::FortyMan),
+            "depth" | "depthchart" | "depth-chart" => Ok(Self::DepthChart),
+            _ => anyhow::bail!("Invalid roster type: {s}; expected 'active', '40man' or 'depth'"),
+        }
+    }
+}
+
+impl fmt::Display for RosterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RosterType::Active => write!(f, "Active Roster"),
+            RosterType::FortyMan => write!(f, "40-Man Roster"),
+            RosterType::DepthChart => write!(f, "Depth Chart"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionGroup {
+    Pitchers,
+    Hitters,
+}
+
+impl RosterEntry {
+    pub fn is_pitcher(&self) -> bool {
+        matches!(self.position.code.as_str(), "1" | "Y")
+    }
+
+    pub fn is_hitter(&self) -> bool {
+        self.position.code != "1"
+    }
+
+    pub fn in_group(&self, group: PositionGroup) -> bool {
+        match group {
+            PositionGroup::Pitchers => self.is_pitcher(),
+            PositionGroup::Hitters => self.is_hitter(),
+        }
+    }
+
+    /// Injured list stints use status codes D7, D10, D15 and D60. Other D codes
+    /// aren't injuries, e.g. DES for designated for assignment.
+    pub fn is_injured(&self) -> bool {
+        matches!(self.status.code.as_str(), "D7" | "D10" | "D15" | "D60")
+            || self.status.description.contains("Injured")
+    }
+}
+
+impl<State> MlbSession<State> {
+    pub async fn fetch_roster(
+        &self,
+        team: &Team,
+        roster_type: RosterType,
+        date: NaiveDate,
+    ) -> Result<Vec<RosterEntry>> {
+        let url = format!("https://statsapi.mlb.com/api/v1/teams/{}/roster", team.id);
+        let date = date.to_string();
+
+        let res = self
+            .client
+            .get(url)
+            .query(&[
+                ("rosterType", roster_type.as_api_str()),
+                ("date", date.as_str()),
+                ("hydrate", "person"),
+            ])
+            .send()
+            .await
+            .context("Failed to send roster request")?
+            .error_for_status()
+            .context("Roster fetch returned unsuccessful status")?;
+
+        let body: RosterResponse = res
+            .json()
+            .await
+            .context("Failed to parse roster response")?;
+
+        Ok(body.roster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(code: &str, description: &str) -> RosterEntry {
+        serde_json::from_value(serde_json::json!({
+            "person": {"id": 1, "fullName": "Test Player"},
+            "position": {"code": "1", "abbreviation": "P"},
+            "status": {"code": code, "description": description},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn only_injured_list_statuses_are_injured() {
+        assert!(entry("D15", "Injured 15-Day").is_injured());
+        assert!(entry("D60", "Injured 60-Day").is_injured());
+        assert!(!entry("DES", "Designated for Assignment").is_injured());
+        assert!(!entry("A", "Active").is_injured());
+    }
+}
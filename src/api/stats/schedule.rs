@@ -1,11 +1,11 @@
 use crate::api::session::MlbSession;
+use crate::api::stats::people::{StatGroupResult, StatLine};
 use crate::data::teamdata::{Division, League, TEAMS, Team, TeamCode};
-use crate::config::AppConfig;
 
 use anyhow::{Context, Result};
 use chrono::{Datelike, NaiveDate};
 use serde::Deserialize;
-use serde::de::{self, Deserializer, Unexpected};
+use serde::de::{self, Deserializer, IgnoredAny, Unexpected};
 use std::fmt;
 use std::str::FromStr;
 
@@ -14,27 +14,6 @@ struct ScheduleResponse {
     dates: Vec<DaySchedule>,
 }
 
-#[derive(Clone, Debug)]
-pub struct GameDate(pub NaiveDate);
-
-impl std::str::FromStr for GameDate {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Try to parse a few common date formats before giving up.
-        let formats = ["%Y-%m-%d", "%m-%d-%Y", "%m/%d/%Y"];
-        for fmt in formats {
-            if let Ok(date) = NaiveDate::parse_from_str(s, fmt) {
-                if date.year() < 2022 {
-                    anyhow::bail!("MLB.tv archives only go back to the start of 2022.");
-                }
-                return Ok(GameDate(date));
-            }
-        }
-        anyhow::bail!("Invalid date format: '{s}'; expected YYYY-MM-DD");
-    }
-}
-
 #[derive(Debug, Deserialize)]
 pub struct DaySchedule {
     pub date: NaiveDate,
@@ -50,6 +29,8 @@ pub struct GameData {
     pub teams: Matchup,
     pub linescore: Option<Linescore>, // May be missing for rescheduled games.
     pub broadcasts: Option<Vec<Broadcast>>, // May be missing for future-dated games.
+    pub venue: Option<Venue>,
+    pub weather: Option<Weather>, // Requires the weather hydration; empty until near game time.
     pub content: Content,
     pub game_number: u8, // Used for double-headers
     pub games_in_series: u8,
@@ -75,6 +56,36 @@ pub struct Matchup {
 #[derive(Debug, Deserialize)]
 pub struct Content {
     pub media: Option<Media>,
+    pub editorial: Option<Editorial>, // Only populated for games with MLB.com coverage.
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Editorial {
+    pub preview: Option<EditorialPreview>,
+    pub recap: Option<EditorialRecap>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EditorialPreview {
+    pub articles: Option<ArticleList>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArticleList {
+    pub items: Vec<Article>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EditorialRecap {
+    pub mlb: Option<Article>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Article {
+    pub headline: Option<String>,
+    pub subhead: Option<String>,
+    pub blurb: Option<String>,
+    pub body: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -112,6 +123,16 @@ impl<'de> Deserialize<'de> for HighlightType {
     }
 }
 
+impl HighlightType {
+    /// The feed name in machine-readable playback records.
+    pub fn feed_name(self) -> &'static str {
+        match self {
+            HighlightType::CondensedGame => "condensed",
+            HighlightType::Recap => "recap",
+        }
+    }
+}
+
 impl fmt::Display for HighlightType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -138,6 +159,38 @@ struct HighlightPlayback {
 #[serde(rename_all = "camelCase")]
 pub struct GameTeamStats {
     pub team: GameTeam,
+    pub league_record: Option<LeagueRecord>,
+    pub probable_pitcher: Option<ProbablePitcher>, // Missing until a starter is announced.
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeagueRecord {
+    pub wins: u16,
+    pub losses: u16,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbablePitcher {
+    pub full_name: String,
+    #[serde(default)]
+    pub stats: Vec<StatGroupResult>,
+}
+
+impl ProbablePitcher {
+    /// Season pitching line from the hydrated stats, if the pitcher has appeared this season.
+    pub fn season_line(&self) -> Option<&StatLine> {
+        self.stats
+            .first()
+            .and_then(|group| group.splits.first())
+            .map(|split| &split.stat)
+    }
+
+    pub fn last_name(&self) -> &str {
+        self.full_name
+            .split_once(' ')
+            .map_or(self.full_name.as_str(), |(_, last)| last)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -152,6 +205,17 @@ pub struct Linescore {
     pub teams: ScoreTeams,
     pub current_inning: Option<u8>,
     pub is_top_inning: Option<bool>,
+    pub balls: Option<u8>,
+    pub strikes: Option<u8>,
+    pub outs: Option<u8>,
+    pub offense: Option<Offense>, // Only populated while a game is in progress.
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Offense {
+    pub first: Option<IgnoredAny>,
+    pub second: Option<IgnoredAny>,
+    pub third: Option<IgnoredAny>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -164,6 +228,25 @@ pub struct ScoreTeams {
 #[serde(rename_all = "camelCase")]
 pub struct Score {
     pub runs: Option<u8>,
+    pub hits: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Venue {
+    pub name: String,
+    pub time_zone: Option<VenueTimeZone>, // Requires the venue(timezone) hydration.
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Weather {
+    pub condition: Option<String>,
+    pub temp: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VenueTimeZone {
+    pub id: String, // IANA name, e.g. "America/New_York"
 }
 
 #[derive(Debug, Deserialize)]
@@ -171,6 +254,7 @@ pub struct Score {
 pub struct Broadcast {
     #[serde(rename = "type")]
     pub kind: String,
+    pub call_sign: Option<String>,
     pub is_national: bool,
     pub home_away: String,
     pub available_for_streaming: bool,
@@ -182,6 +266,19 @@ pub struct ScheduleFilter {
     pub league: Option<Vec<League>>,
     pub division: Option<Vec<Division>>,
     pub team: Option<Vec<Team>>,
+    /// `favs` was given; filled in from the config by `resolve_favorites`.
+    pub favorites: bool,
+}
+
+impl ScheduleFilter {
+    /// Replace a `favs` filter entry with the configured favorite teams.
+    pub fn resolve_favorites(&mut self, favorites: &[TeamCode]) {
+        if !std::mem::take(&mut self.favorites) {
+            return;
+        }
+        let teams = self.team.get_or_insert_with(Vec::new);
+        teams.extend(favorites.iter().map(|&code| *code.team()));
+    }
 }
 
 impl FromStr for ScheduleFilter {
@@ -200,11 +297,8 @@ impl FromStr for ScheduleFilter {
                 let team = team_code.team();
                 filter.team.get_or_insert_with(Vec::new).push(*team);
             } else if part == "favs" {
-                let config = AppConfig::load()?;
-                for team_code in config.favorites.teams {
-                    let team = team_code.team();
-                    filter.team.get_or_insert_with(Vec::new).push(*team);
-                }
+                // The config isn't loaded yet while arguments are parsed.
+                filter.favorites = true;
             } else {
                 return Err(anyhow::anyhow!("Invalid filter value: {}", part));
             }
@@ -270,14 +364,19 @@ impl<State> MlbSession<State> {
             Some(filter) => prepare_filters(filter),
             None => String::new(),
         };
-        let hydrate = concat!(
-            "hydrate=,",
-            "broadcasts(all),",
-            "game(content(media(epg)),",
-            "editorial(preview,recap)),",
-            "linescore,",
-            "team,",
-            "probablePitcher(note)",
+        let hydrate = format!(
+            concat!(
+                "hydrate=,",
+                "broadcasts(all),",
+                "game(content(media(epg)),",
+                "editorial(preview,recap)),",
+                "linescore,",
+                "team,",
+                "venue(timezone),",
+                "weather,",
+                "probablePitcher(note,stats(type=[season],group=[pitching],season={season}))",
+            ),
+            season = start_date.year()
         );
         let url = format!(
             "https://statsapi.mlb.com/api/v1/schedule?sportId=1&startDate={s}&endDate={e}{f}&{h}",
@@ -337,14 +436,14 @@ impl<State> MlbSession<State> {
         }
     }
 
-    pub async fn find_highlight_playback_url(
+    /// Find a team's game on the given date, resolving doubleheaders with `select_game`.
+    pub async fn find_team_game(
         &self,
         team: &Team,
         date: NaiveDate,
-        highlight_type: HighlightType,
         game_number: Option<u8>,
         filter: Option<&ScheduleFilter>,
-    ) -> Result<Option<String>> {
+    ) -> Result<Option<GameData>> {
         let Some(team_games) = self
             .fetch_schedule_by_date(&date, filter)
             .await?
@@ -354,8 +453,23 @@ impl<State> MlbSession<State> {
             return Ok(None);
         };
 
+        select_game(team_games, game_number).map(Some)
+    }
+
+    /// The highlight's URL, with the `game_pk` of the game it comes from.
+    pub async fn find_highlight_playback_url(
+        &self,
+        team: &Team,
+        date: NaiveDate,
+        highlight_type: HighlightType,
+        game_number: Option<u8>,
+        filter: Option<&ScheduleFilter>,
+    ) -> Result<Option<(u64, String)>> {
+        let Some(game_data) = self.find_team_game(team, date, game_number, filter).await? else {
+            return Ok(None);
+        };
+
         // TODO: Do we want to hard code high bitrate here?
-        let game_data = select_game(team_games, game_number)?;
         let Some(url) = game_data.find_highlight(highlight_type, "highBit") else {
             println!(
                 "No high bitrate {} found for the {} on {}",
@@ -364,7 +478,7 @@ impl<State> MlbSession<State> {
             return Ok(None);
         };
 
-        Ok(Some(url))
+        Ok(Some((game_data.game_pk, url)))
     }
 }
 
@@ -388,7 +502,86 @@ impl DaySchedule {
 }
 
 impl GameData {
-    fn find_highlight(&self, highlight_type: HighlightType, quality: &str) -> Option<String> {
+    /// True while the game is being played, including manager challenges and review delays.
+    pub fn is_in_progress(&self) -> bool {
+        self.status.status_code == "I" || matches!(self.status.coded_game_state.as_str(), "M" | "N")
+    }
+
+    /// The ballpark's time zone, if the venue was hydrated with one we recognize.
+    pub fn venue_time_zone(&self) -> Option<chrono_tz::Tz> {
+        self.venue.as_ref()?.time_zone.as_ref()?.id.parse().ok()
+    }
+
+    /// English feeds available for streaming, sorted, e.g. ["away", "home", "national"].
+    pub fn streaming_feeds(&self, tv: bool) -> Vec<String> {
+        let Some(broadcasts) = &self.broadcasts else {
+            return Vec::new();
+        };
+
+        let mut feeds: Vec<String> = broadcasts
+            .iter()
+            .filter(|b| (b.kind == "TV") == tv && b.language == "en" && b.available_for_streaming)
+            .map(|b| match b.is_national && tv {
+                true => "national".to_string(),
+                false => b.home_away.to_lowercase(),
+            })
+            .collect();
+        feeds.sort();
+        feeds.dedup();
+        feeds
+    }
+
+    /// Runs separating the two teams, once the game has started.
+    pub fn run_margin(&self) -> Option<u8> {
+        if self.status.abstract_game_state == "Preview" {
+            return None;
+        }
+        let teams = &self.linescore.as_ref()?.teams;
+        Some(teams.away.runs?.abs_diff(teams.home.runs?))
+    }
+
+    /// A close game from `min_inning` on, any extra-inning game, or a no-hitter in progress.
+    pub fn is_critical(&self, min_inning: u8, max_run_margin: u8) -> bool {
+        if !self.is_in_progress() {
+            return false;
+        }
+        let Some(linescore) = &self.linescore else {
+            return false;
+        };
+        let Some(inning) = linescore.current_inning else {
+            return false;
+        };
+
+        let (away, home) = (&linescore.teams.away, &linescore.teams.home);
+        let margin = away.runs.unwrap_or(0).abs_diff(home.runs.unwrap_or(0));
+        let no_hitter = away.hits == Some(0) || home.hits == Some(0);
+
+        inning > 9 || (inning >= min_inning && (margin <= max_run_margin || no_hitter))
+    }
+
+    pub fn preview_article(&self) -> Option<&Article> {
+        self.content
+            .editorial
+            .as_ref()?
+            .preview
+            .as_ref()?
+            .articles
+            .as_ref()?
+            .items
+            .first()
+    }
+
+    pub fn recap_article(&self) -> Option<&Article> {
+        self.content
+            .editorial
+            .as_ref()?
+            .recap
+            .as_ref()?
+            .mlb
+            .as_ref()
+    }
+
+    pub fn find_highlight(&self, highlight_type: HighlightType, quality: &str) -> Option<String> {
         self.content
             .media
             .as_ref()?
@@ -444,7 +637,6 @@ pub fn select_game(mut team_games: Vec<GameData>, game_number: Option<u8>) -> Re
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::str::FromStr;
 
     fn mock_game(pk: u64, state: &str, game_number: u8) -> GameData {
         GameData {
@@ -463,27 +655,44 @@ mod tests {
                         id: 120,
                         name: "Washington Nationals".to_string(),
                     },
+                    league_record: None,
+                    probable_pitcher: None,
                 },
                 away: GameTeamStats {
                     team: GameTeam {
                         id: 141,
                         name: "Toronto Blue Jays".to_string(),
                     },
+                    league_record: None,
+                    probable_pitcher: None,
                 },
             },
             linescore: Some(Linescore {
                 current_inning: Some(7),
                 is_top_inning: Some(true),
+                balls: None,
+                strikes: None,
+                outs: None,
+                offense: None,
                 teams: ScoreTeams {
-                    home: Score { runs: Some(3) },
-                    away: Score { runs: Some(2) },
+                    home: Score {
+                        runs: Some(3),
+                        hits: Some(6),
+                    },
+                    away: Score {
+                        runs: Some(2),
+                        hits: Some(5),
+                    },
                 },
             }),
             broadcasts: Some(vec![]),
+            venue: None,
+            weather: None,
             content: Content {
                 media: Some(Media {
                     epg_alternate: None,
                 }),
+                editorial: None,
             },
             game_number,
             games_in_series: 3,
@@ -537,22 +746,41 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn mock_live_game(inning: u8, away_runs: u8, home_runs: u8) -> GameData {
+        let mut game = mock_game(12345, "Live", 1);
+        game.status.status_code = "I".to_string();
+        game.status.coded_game_state = "I".to_string();
+        let linescore = game.linescore.as_mut().expect("mock game has a linescore");
+        linescore.current_inning = Some(inning);
+        linescore.teams.away.runs = Some(away_runs);
+        linescore.teams.home.runs = Some(home_runs);
+        game
+    }
+
     #[test]
-    fn game_date_from_str_parses_valid_formats() {
-        assert!(GameDate::from_str("2024-10-01").is_ok());
-        assert!(GameDate::from_str("10-01-2024").is_ok());
-        assert!(GameDate::from_str("10/01/2024").is_ok());
+    fn is_critical_flags_close_late_games() {
+        assert!(mock_live_game(8, 2, 3).is_critical(7, 1));
+        assert!(!mock_live_game(8, 1, 5).is_critical(7, 1));
+        assert!(!mock_live_game(4, 2, 3).is_critical(7, 1));
     }
 
     #[test]
-    fn game_date_from_str_rejects_old_dates() {
-        let result = GameDate::from_str("2021-10-01");
-        assert!(result.is_err());
+    fn is_critical_flags_extra_innings_and_no_hitters() {
+        assert!(mock_live_game(11, 0, 6).is_critical(7, 1));
+
+        let mut game = mock_live_game(8, 0, 6);
+        game.linescore.as_mut().unwrap().teams.away.hits = Some(0);
+        assert!(game.is_critical(7, 1));
     }
 
     #[test]
-    fn game_date_from_str_rejects_invalid_format() {
-        let result = GameDate::from_str("invalid-date");
-        assert!(result.is_err());
+    fn run_margin_needs_a_started_game() {
+        assert_eq!(mock_live_game(5, 4, 1).run_margin(), Some(3));
+        assert_eq!(mock_game(12345, "Preview", 1).run_margin(), None);
+    }
+
+    #[test]
+    fn is_critical_ignores_finished_games() {
+        assert!(!mock_game(12345, "Final", 1).is_critical(7, 1));
     }
 }
@@ -0,0 +1,79 @@
+use crate::api::session::MlbSession;
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct SeasonsResponse {
+    #[serde(default)]
+    seasons: Vec<Season>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Season {
+    regular_season_start_date: Option<String>,
+}
+
+impl SeasonsResponse {
+    fn regular_season_start(&self) -> Result<Option<NaiveDate>> {
+        let Some(date) = self
+            .seasons
+            .iter()
+            .find_map(|season| season.regular_season_start_date.as_deref())
+        else {
+            return Ok(None);
+        };
+        let date = date
+            .parse()
+            .with_context(|| format!("Invalid regular season start date: {date}"))?;
+        Ok(Some(date))
+    }
+}
+
+impl<State> MlbSession<State> {
+    /// The first day of the `season`'s regular season, once MLB has published it.
+    pub async fn fetch_regular_season_start(&self, season: i32) -> Result<Option<NaiveDate>> {
+        let res = self
+            .client
+            .get(format!("https://statsapi.mlb.com/api/v1/seasons/{season}"))
+            .query(&[("sportId", "1")])
+            .send()
+            .await
+            .context("Failed to send season request")?
+            .error_for_status()
+            .context("Season fetch returned unsuccessful status")?;
+
+        let body: SeasonsResponse = res
+            .json()
+            .await
+            .context("Failed to parse season response")?;
+
+        body.regular_season_start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_regular_season_start() {
+        let body: SeasonsResponse = serde_json::from_str(
+            r#"{"seasons": [{
+                "seasonId": "2027", "preSeasonStartDate": "2027-01-01",
+                "springStartDate": "2027-02-20", "regularSeasonStartDate": "2027-03-25",
+                "regularSeasonEndDate": "2027-09-26"
+            }]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            body.regular_season_start().unwrap(),
+            NaiveDate::from_ymd_opt(2027, 3, 25)
+        );
+
+        let unpublished: SeasonsResponse = serde_json::from_str(r#"{"seasons": []}"#).unwrap();
+        assert_eq!(unpublished.regular_season_start().unwrap(), None);
+    }
+}
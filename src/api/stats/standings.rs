@@ -0,0 +1,141 @@
+use crate::api::session::MlbSession;
+use crate::data::teamdata::{Division, Team};
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+
+/// American and National League ids.
+const LEAGUE_IDS: &str = "103,104";
+
+#[derive(Debug, Deserialize)]
+struct StandingsResponse {
+    records: Vec<DivisionStandings>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionStandings {
+    pub team_records: Vec<TeamRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TeamRef {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Streak {
+    pub streak_code: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitRecord {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub wins: u32,
+    pub losses: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitRecords {
+    #[serde(default)]
+    pub split_records: Vec<SplitRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRecord {
+    pub team: TeamRef,
+    pub wins: u32,
+    pub losses: u32,
+    pub winning_percentage: String,
+    pub games_back: String,
+    pub streak: Option<Streak>,
+    #[serde(default)]
+    pub records: SplitRecords,
+    pub run_differential: Option<i32>,
+}
+
+impl TeamRecord {
+    /// Wins and losses over the last ten games, e.g. `7-3`.
+    pub fn last_ten(&self) -> Option<String> {
+        self.records
+            .split_records
+            .iter()
+            .find(|split| split.kind == "lastTen")
+            .map(|split| format!("{}-{}", split.wins, split.losses))
+    }
+}
+
+impl DivisionStandings {
+    /// The division, taken from its first team since the response only carries an id.
+    pub fn division(&self) -> Option<&'static Division> {
+        let team = self.team_records.first()?;
+        Team::try_find_by_id(&team.team.id).map(|team| team.division)
+    }
+}
+
+impl<State> MlbSession<State> {
+    /// Regular season division standings as of `date`.
+    pub async fn fetch_standings(&self, date: NaiveDate) -> Result<Vec<DivisionStandings>> {
+        let season = date.year().to_string();
+        let date = date.to_string();
+
+        let res = self
+            .client
+            .get("https://statsapi.mlb.com/api/v1/standings")
+            .query(&[
+                ("leagueId", LEAGUE_IDS),
+                ("season", season.as_str()),
+                ("date", date.as_str()),
+                ("standingsTypes", "regularSeason"),
+            ])
+            .send()
+            .await
+            .context("Failed to send standings request")?
+            .error_for_status()
+            .context("Standings fetch returned unsuccessful status")?;
+
+        let body: StandingsResponse = res
+            .json()
+            .await
+            .context("Failed to parse standings response")?;
+
+        Ok(body.records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_team_records_and_last_ten() {
+        let body: StandingsResponse = serde_json::from_str(
+            r#"{"records": [{"teamRecords": [{
+                "team": {"id": 120, "name": "Washington Nationals"},
+                "wins": 81, "losses": 70, "winningPercentage": ".536", "gamesBack": "4.0",
+                "streak": {"streakCode": "W3"}, "runDifferential": -12,
+                "records": {"splitRecords": [
+                    {"type": "home", "wins": 40, "losses": 35},
+                    {"type": "lastTen", "wins": 7, "losses": 3}
+                ]}
+            }]}]}"#,
+        )
+        .unwrap();
+
+        let division = &body.records[0];
+        let record = &division.team_records[0];
+        assert_eq!(record.last_ten().as_deref(), Some("7-3"));
+        assert_eq!(record.run_differential, Some(-12));
+        assert_eq!(
+            division.division().map(|d| d.name),
+            Team::try_find_by_id(&120).map(|team| team.division.name)
+        );
+    }
+}
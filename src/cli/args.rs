@@ -1,10 +1,24 @@
-use chrono::{Duration, Local};
-use clap::{ArgGroup, Parser};
+use chrono::{Datelike, Duration, Local};
+use clap::{ArgGroup, Parser, Subcommand};
+use clap_complete::Shell;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 
 use crate::api::mediagateway::streams::{FeedType, MediaType};
-use crate::api::stats::schedule::{GameDate, ScheduleFilter};
+use crate::api::stats::feed::PlayFilter;
+use crate::api::stats::roster::{PositionGroup, RosterType};
+use crate::api::stats::schedule::ScheduleFilter;
+use crate::cli::completions;
+use crate::cli::dates::{self, GameDate};
+use crate::cli::display::DisplayMode;
+use crate::cli::output::OutputFormat;
+use crate::config::overrides;
+use crate::config::{ConfigSources, GroupBy, SortOrder, TimeZoneSetting};
 use crate::data::teamdata::TeamCode;
 
+/// Days covered by calendar exports when --days isn't given.
+const CALENDAR_DAYS: i64 = 30;
+
 /// Stream live/archived MLB.tv games; view stats, schedules and highlights
 #[derive(Parser, Debug)]
 #[command(
@@ -14,65 +28,164 @@ use crate::data::teamdata::TeamCode;
                   Stream live and archived games with your MLB.tv subscription.\n\
                   View stats, check schedules and watch highlights for free.\n\n\
                   EXAMPLES:\n  \
-                  mlbv-rs --team wsh                     # Watch today's Nationals game\n  \
-                  mlbv-rs --team bos --yesterday         # Yesterday's Red Sox game\n  \
-                  mlbv-rs --team nym --condensed         # Condensed game for Mets\n  \
-                  mlbv-rs --recap --yesterday            # Play all recaps from yesterday\n  \
-                  mlbv-rs --days 7                       # Show schedule for next 7 days\n  \
-                  mlbv-rs --date 2024-10-01 --team lad   # Dodgers game on specific date"
+                  mlbv-rs play wsh                       # Watch today's Nationals game\n  \
+                  mlbv-rs play bos --yesterday           # Yesterday's Red Sox game\n  \
+                  mlbv-rs condensed nym                  # Condensed game for Mets\n  \
+                  mlbv-rs recap --yesterday              # Play all recaps from yesterday\n  \
+                  mlbv-rs schedule --days 7              # Show schedule for next 7 days\n  \
+                  mlbv-rs play lad --date 2024-10-01     # Dodgers game on specific date\n  \
+                  mlbv-rs player juan soto               # Juan Soto's stats and game log\n  \
+                  mlbv-rs roster sea 40man               # Mariners 40-man roster\n  \
+                  mlbv-rs info chc --yesterday           # Recap of yesterday's Cubs game\n  \
+                  mlbv-rs plays tor all                  # Blue Jays play-by-play\n  \
+                  mlbv-rs standings                      # Division standings\n  \
+                  mlbv-rs watch                          # Live scoreboard, refreshed every 30s\n  \
+                  mlbv-rs tui                            # Browse games and feeds interactively\n  \
+                  mlbv-rs schedule --days 7 --output csv # Schedule as CSV for scripts\n  \
+                  mlbv-rs calendar wsh.ics --filter wsh  # Nationals games as a calendar file\n\n\
+                  The flag style of the Python mlbv still works, e.g. `mlbv-rs --team wsh --condensed`."
 )]
 #[command(group(
     ArgGroup::new("date_group")
-        .args(["date", "days", "tomorrow", "yesterday"])
+        .args(["date", "tomorrow", "yesterday"])
         .multiple(false)
         .required(false)
 ))]
 pub struct Cli {
-    /// Re-initialize config file (prompts for MLB.tv credentials)
-    #[arg(long)]
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Create the config file, or update the MLB.tv credentials in an existing one
+    #[arg(long, conflicts_with = "output")]
     pub init: bool,
 
+    /// MLB.tv username for --init, instead of prompting
+    #[arg(long, requires = "init", value_name = "NAME")]
+    pub username: Option<String>,
+
+    /// Read the MLB.tv password for --init from the first line of stdin
+    #[arg(long, requires = "username")]
+    pub password_stdin: bool,
+
     /// Team code to watch or filter by (e.g., wsh, cle, bos, lad)
-    #[arg(short, long)]
+    #[arg(
+        short,
+        long,
+        value_parser = completions::teams(),
+        hide_possible_values = true
+    )]
     pub team: Option<TeamCode>,
 
-    /// Specific date; defaults to today if none provided
+    /// Specific date or date range; defaults to today if none provided
     #[arg(
         short,
         long,
+        allow_hyphen_values = true,
+        global = true,
         long_help = "Specify a date for games. Accepts multiple formats:\n  \
                      YYYY-MM-DD (e.g., 2024-10-01)\n  \
                      MM-DD-YYYY (e.g., 10-01-2024)\n  \
-                     MM/DD/YYYY (e.g., 10/01/2024)\n\
+                     MM/DD/YYYY (e.g., 10/01/2024)\n  \
+                     Days from today (e.g., -3, +2) or today/yesterday/tomorrow\n  \
+                     Weekdays (e.g., friday, \"last friday\", \"next tue\")\n  \
+                     Month and day (e.g., \"july 4\", \"sep 28 2024\")\n  \
+                     opening-day, or opening-day-YYYY for another season\n\
+                     Schedules and calendar exports also take a range, e.g. 2024-09-01..2024-09-07.\n\
                      Defaults to today if not specified."
     )]
     pub date: Option<GameDate>,
 
     /// Shortcut for tomorrow's date
-    #[arg(long)]
+    #[arg(long, global = true, conflicts_with_all(["date", "yesterday"]))]
     pub tomorrow: bool,
 
     /// Shortcut for yesterday's date
-    #[arg(long)]
+    #[arg(long, global = true, conflicts_with = "date")]
     pub yesterday: bool,
 
-    /// Display schedule for N days from today; accepts negative values
+    /// Display schedule for N days from --date (default today); accepts negative values
     #[arg(
         long,
+        allow_negative_numbers = true,
         conflicts_with("team"),
-        long_help = "Show schedule for multiple days:\n  \
+        long_help = "Show schedule for multiple days, counted from --date (default today):\n  \
                      Positive: future days (--days 7 shows next week)\n  \
                      Negative: past days (--days -3 shows last 3 days)\n\
                      Cannot be used with --team."
     )]
     pub days: Option<i64>,
 
+    /// Live scoreboard: redraw the day's schedule every N seconds (default 30)
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        default_missing_value = "30",
+        value_parser = clap::value_parser!(u64).range(10..),
+        conflicts_with_all(["team", "days", "recap", "info", "player", "output"]),
+        long_help = "Keep the schedule on screen and refresh it every N seconds (minimum 10).\n\
+                     In-progress games show the count, outs and runners on base.\n\
+                     Rows flash when a score changes (only when scores are shown).\n\
+                     Press Ctrl-C to quit."
+    )]
+    pub watch: Option<u64>,
+
+    /// Browse games, feeds and highlights in a full-screen terminal UI
+    #[arg(
+        long,
+        conflicts_with_all(["team", "days", "recap", "info", "player", "watch", "output"]),
+        long_help = "Open a full-screen game browser starting on --date (default today).\n\
+                     Move between days, open a game to list its feeds and highlights,\n\
+                     and launch the video player without leaving the app.\n\
+                     Live/archived feeds require MLB.tv credentials; highlights are free."
+    )]
+    pub tui: bool,
+
+    /// Export upcoming games as an iCalendar (.ics) file, or to stdout with no FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        default_missing_value = "-",
+        conflicts_with_all(["team", "recap", "info", "player", "watch", "tui"]),
+        long_help = "Write games to an iCalendar file that calendar apps can import.\n\
+                     Covers 30 days from --date (default today), or the --days range.\n\
+                     Use --filter to pick teams, divisions or leagues (e.g. --filter favs).\n\
+                     Each event is keyed by its game ID, so re-importing updates existing events."
+    )]
+    pub ics: Option<PathBuf>,
+
+    /// Serve a calendar subscription feed over HTTP (default 127.0.0.1:8642)
+    #[arg(
+        long,
+        value_name = "ADDR",
+        num_args = 0..=1,
+        default_missing_value = "127.0.0.1:8642",
+        conflicts_with_all(["team", "recap", "info", "player", "watch", "tui", "ics", "date"]),
+        long_help = "Run a small HTTP server that regenerates the calendar on every request,\n\
+                     so calendar clients subscribed to http://ADDR/mlb.ics stay current.\n\
+                     Covers the next 30 days, or --days relative to today. Honors --filter.\n\
+                     Bind to 0.0.0.0:PORT to share the feed with other machines."
+    )]
+    pub serve_ics: Option<SocketAddr>,
+
     /// Filter schedule or recaps by league, division, or team
-    #[arg(short = 'o', long)]
+    #[arg(
+        short = 'o',
+        long,
+        value_parser = completions::filters(),
+        hide_possible_values = true,
+        global = true
+    )]
     pub filter: Option<ScheduleFilter>,
 
     /// Select feed type: home, away, or national
-    #[arg(short, long)]
+    #[arg(
+        short,
+        long,
+        value_parser = completions::feeds(),
+        hide_possible_values = true
+    )]
     pub feed: Option<FeedType>,
 
     /// Select audio-only broadcast
@@ -103,10 +216,82 @@ pub struct Cli {
     )]
     pub recap: bool,
 
+    /// Show game preview or recap articles
+    #[arg(
+        long,
+        conflicts_with_all(["recap", "condensed", "days", "output"]),
+        long_help = "Print the preview article before a game, or the recap article after it.\n\
+                     With --team: articles for that team's game only\n\
+                     Without --team: articles for every game on the day (honors --filter)\n\
+                     Recaps are hidden for games whose scores are hidden."
+    )]
+    pub info: bool,
+
+    /// Show scoring plays (default) or full play-by-play for a team's game
+    #[arg(
+        long,
+        value_name = "WHICH",
+        num_args = 0..=1,
+        default_missing_value = "scoring",
+        requires("team"),
+        conflicts_with_all(["recap", "condensed", "info", "output"]),
+        long_help = "Print plays from the live game feed for the team given by --team.\n  \
+                     scoring: Scoring plays only (default)\n  \
+                     all:     Full play-by-play, grouped by inning\n\
+                     Use --game-number to pick a doubleheader game and --pitches for pitch sequences.\n\
+                     Plays are hidden for games whose scores are hidden."
+    )]
+    pub plays: Option<PlayFilter>,
+
+    /// Include pitch-by-pitch sequences in --plays output
+    #[arg(long, requires("plays"))]
+    pub pitches: bool,
+
+    /// Look up a player's bio, season/career stats, game log and splits
+    #[arg(
+        long,
+        value_name = "NAME",
+        conflicts_with_all(["team", "recap", "condensed", "days", "output"]),
+        long_help = "Search for a player by name and show their bio, season and career stats,\n\
+                     recent game log and splits (vs LHP/RHP, home/away).\n\
+                     Misspelled names are matched to the closest player.\n\
+                     Stats are for the season of --date (defaults to the current season)."
+    )]
+    pub player: Option<String>,
+
+    /// Number of recent games to show in the player game log
+    #[arg(long, value_name = "N", default_value_t = 5, requires = "player")]
+    pub games: usize,
+
+    /// Show a team roster: active (default), 40man, or depth
+    #[arg(
+        long,
+        value_name = "TYPE",
+        num_args = 0..=1,
+        default_missing_value = "active",
+        requires("team"),
+        conflicts_with_all(["recap", "condensed", "output"]),
+        long_help = "Show the roster for the team given by --team.\n  \
+                     active: 26-man active roster (default)\n  \
+                     40man:  40-man roster, including injured list status\n  \
+                     depth:  Depth chart by position\n\
+                     Use --date to view the roster as of a past date."
+    )]
+    pub roster: Option<RosterType>,
+
+    /// Only show pitchers in roster output
+    #[arg(long, requires("roster"), conflicts_with("hitters"))]
+    pub pitchers: bool,
+
+    /// Only show position players in roster output
+    #[arg(long, requires("roster"), conflicts_with("pitchers"))]
+    pub hitters: bool,
+
     /// Game number for doubleheaders (1 or 2)
     #[arg(
         short,
         long,
+        global = true,
         long_help = "Specify which game to watch during doubleheaders.\n\
                      Valid values: 1 or 2\n\
                      If not specified, live game is preferred, otherwise defaults to game 1."
@@ -114,21 +299,74 @@ pub struct Cli {
     pub game_number: Option<u8>,
 
     /// Print stream URL without launching player
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub url: bool,
 
-    /// Show scores (overrides config file setting)
-    #[arg(long, conflicts_with = "no_scores")]
+    /// Output format for schedules and stream URLs: table, json, csv or ndjson
+    #[arg(
+        long,
+        global = true,
+        value_name = "FORMAT",
+        default_value = "table",
+        long_help = "Print schedules and resolved stream URLs in a machine-readable format.\n  \
+                     table:  Formatted table (default)\n  \
+                     json:   A JSON array of records\n  \
+                     csv:    One row per record with a header line\n  \
+                     ndjson: One JSON record per line\n\
+                     Any format other than table prints stream URLs instead of launching the player."
+    )]
+    pub output: OutputFormat,
+
+    /// Force a table layout instead of sizing to the terminal: standard, condensed or compact
+    #[arg(long, value_name = "MODE", global = true)]
+    pub display_mode: Option<DisplayMode>,
+
+    /// Order games by time, status (live first) or closeness (smallest run margin first)
+    #[arg(long, value_name = "ORDER", global = true)]
+    pub sort: Option<SortOrder>,
+
+    /// Split schedules into tables by favorites, division or time (none for one table)
+    #[arg(long, value_name = "GROUP", global = true)]
+    pub group_by: Option<GroupBy>,
+
+    /// Time zone for game times: local, ballpark, or a name like America/New_York
+    #[arg(long, value_name = "ZONE", global = true)]
+    pub tz: Option<TimeZoneSetting>,
+
+    /// Show scores and results for every game (overrides config file settings)
+    #[arg(long, conflicts_with = "no_scores", global = true)]
     pub scores: bool,
 
-    /// Hide scores (overrides config file setting)
-    #[arg(long, conflicts_with = "scores")]
+    /// Spoiler-free: hide scores, innings, final states, recaps and plays
+    #[arg(long, conflicts_with = "scores", global = true)]
     pub no_scores: bool,
 
+    /// Use an alternate config file (or set MLBV_CONFIG)
+    #[arg(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Use the settings in the config file's [profile.NAME] section (or set MLBV_PROFILE)
+    #[arg(long, value_name = "NAME", global = true)]
+    pub profile: Option<String>,
+
+    /// Override a config key for this run; repeatable
+    #[arg(
+        long,
+        global = true,
+        value_name = "KEY=VALUE",
+        value_parser = overrides::parse_setting,
+        long_help = "Override a config key for this run, e.g. --set stream.video_player=vlc\n\
+                     Lists are comma separated: --set favorites.teams=wsh,tor\n\
+                     Precedence: defaults < config file < --profile < MLBV_* variables < --set\n\
+                     Environment variables name the table and key, e.g. MLBV_CREDENTIALS_USERNAME."
+    )]
+    pub set: Vec<(String, String)>,
+
     /// Verbose logging (-v, -vv, -vvv for more detail)
     #[arg(
         short,
         long,
+        global = true,
         action = clap::ArgAction::Count,
         long_help = "Control logging level:\n  \
                      (none): Warnings only\n  \
@@ -139,8 +377,186 @@ pub struct Cli {
     pub verbose: u8,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Watch a team's live or archived game
+    Play {
+        /// Team code, e.g. wsh
+        #[arg(value_parser = completions::teams(), hide_possible_values = true)]
+        team: TeamCode,
+
+        /// Select feed type: home, away, or national
+        #[arg(
+            short,
+            long,
+            value_parser = completions::feeds(),
+            hide_possible_values = true
+        )]
+        feed: Option<FeedType>,
+
+        /// Select audio-only broadcast
+        #[arg(long)]
+        audio: bool,
+    },
+    /// Play a team's condensed game (~10 min)
+    Condensed {
+        /// Team code, e.g. nym
+        #[arg(value_parser = completions::teams(), hide_possible_values = true)]
+        team: TeamCode,
+    },
+    /// Play the recap (~3-5 min) of a team's game, or of every game on the day
+    Recap {
+        /// Team code; all games (honoring --filter) when left out
+        #[arg(value_parser = completions::teams(), hide_possible_values = true)]
+        team: Option<TeamCode>,
+    },
+    /// Show the schedule for a day, or for a range of days
+    Schedule {
+        /// Show N days from --date (default today); accepts negative values
+        #[arg(long, allow_negative_numbers = true)]
+        days: Option<i64>,
+    },
+    /// Live scoreboard: redraw the day's schedule every SECONDS (default 30)
+    Watch {
+        #[arg(
+            default_value_t = 30,
+            value_parser = clap::value_parser!(u64).range(10..)
+        )]
+        seconds: u64,
+    },
+    /// Browse games, feeds and highlights in a full-screen terminal UI
+    Tui,
+    /// Show game preview or recap articles
+    Info {
+        /// Team code; every game on the day (honoring --filter) when left out
+        #[arg(value_parser = completions::teams(), hide_possible_values = true)]
+        team: Option<TeamCode>,
+    },
+    /// Show scoring plays (default) or full play-by-play for a team's game
+    Plays {
+        /// Team code, e.g. tor
+        #[arg(value_parser = completions::teams(), hide_possible_values = true)]
+        team: TeamCode,
+
+        /// scoring or all
+        #[arg(default_value = "scoring")]
+        which: PlayFilter,
+
+        /// Include pitch-by-pitch sequences
+        #[arg(long)]
+        pitches: bool,
+    },
+    /// Look up a player's bio, season/career stats, game log and splits
+    Player {
+        /// Player name; misspellings are matched to the closest player
+        #[arg(required = true, num_args = 1..)]
+        name: Vec<String>,
+
+        /// Number of recent games to show in the game log
+        #[arg(long, value_name = "N", default_value_t = 5)]
+        games: usize,
+    },
+    /// Show a team roster: active (default), 40man, or depth
+    Roster {
+        /// Team code, e.g. sea
+        #[arg(value_parser = completions::teams(), hide_possible_values = true)]
+        team: TeamCode,
+
+        #[arg(default_value = "active")]
+        kind: RosterType,
+
+        /// Only show pitchers
+        #[arg(long, conflicts_with = "hitters")]
+        pitchers: bool,
+
+        /// Only show position players
+        #[arg(long, conflicts_with = "pitchers")]
+        hitters: bool,
+    },
+    /// Show division standings as of --date (default today)
+    Standings,
+    /// Export upcoming games as an iCalendar (.ics) file, or serve them over HTTP
+    Calendar {
+        /// File to write; stdout when left out
+        #[arg(conflicts_with = "serve")]
+        file: Option<PathBuf>,
+
+        /// Days covered, counted from --date (default 30); accepts negative values
+        #[arg(long, allow_negative_numbers = true)]
+        days: Option<i64>,
+
+        /// Serve a subscription feed at http://ADDR/mlb.ics (default 127.0.0.1:8642)
+        #[arg(
+            long,
+            value_name = "ADDR",
+            num_args = 0..=1,
+            default_missing_value = "127.0.0.1:8642"
+        )]
+        serve: Option<SocketAddr>,
+    },
+    /// Inspect and edit the config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Print a shell completion script, e.g. `mlbv completions zsh > ~/.zfunc/_mlbv`
+    Completions { shell: Shell },
+    /// Print the man page, e.g. `mlbv man > ~/.local/share/man/man1/mlbv.1`
+    Man,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// Check every key and value, reporting problems by line and column
+    Check,
+    /// Set a key in the config file, e.g. `config set stream.video_player vlc`
+    Set { key: String, value: String },
+    /// Print a key's value after profiles, MLBV_* variables and --set are applied
+    Get { key: String },
+    /// Remove a key from the config file so its default applies
+    Unset { key: String },
+    /// Print every key that is set, with passwords masked
+    List,
+}
+
 pub enum CliMode {
     Init,
+    ConfigCheck,
+    ConfigSet {
+        key: String,
+        value: String,
+    },
+    ConfigGet {
+        key: String,
+    },
+    ConfigUnset {
+        key: String,
+    },
+    ConfigList,
+    PlayerStats {
+        name: String,
+        season: i32,
+        games: usize,
+    },
+    Roster {
+        team_code: TeamCode,
+        date: chrono::NaiveDate,
+        roster_type: RosterType,
+        position_group: Option<PositionGroup>,
+    },
+    Info {
+        date: chrono::NaiveDate,
+        team_code: Option<TeamCode>,
+        game_number: Option<u8>,
+        filter: Option<ScheduleFilter>,
+    },
+    Plays {
+        team_code: TeamCode,
+        date: chrono::NaiveDate,
+        game_number: Option<u8>,
+        filter: PlayFilter,
+        pitches: bool,
+    },
     PlayStream {
         team_code: TeamCode,
         date: chrono::NaiveDate,
@@ -168,26 +584,300 @@ pub enum CliMode {
         date: chrono::NaiveDate,
         filter: Option<ScheduleFilter>,
     },
+    Calendar {
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        filter: Option<ScheduleFilter>,
+        path: Option<PathBuf>,
+    },
+    ServeCalendar {
+        addr: SocketAddr,
+        days: i64,
+        filter: Option<ScheduleFilter>,
+    },
+    Browse {
+        date: chrono::NaiveDate,
+        filter: Option<ScheduleFilter>,
+    },
+    Standings {
+        date: chrono::NaiveDate,
+    },
+    Completions {
+        shell: Shell,
+    },
+    ManPage,
+    WatchSchedule {
+        date: chrono::NaiveDate,
+        filter: Option<ScheduleFilter>,
+        interval_secs: u64,
+    },
+}
+
+impl CliMode {
+    /// The schedule filter, for modes that take one.
+    pub fn filter_mut(&mut self) -> Option<&mut ScheduleFilter> {
+        match self {
+            CliMode::Info { filter, .. }
+            | CliMode::PlayRecap { filter, .. }
+            | CliMode::RangeSchedule { filter, .. }
+            | CliMode::DaySchedule { filter, .. }
+            | CliMode::Calendar { filter, .. }
+            | CliMode::ServeCalendar { filter, .. }
+            | CliMode::Browse { filter, .. }
+            | CliMode::WatchSchedule { filter, .. } => filter.as_mut(),
+            _ => None,
+        }
+    }
 }
 
 impl Cli {
-    pub fn to_mode(&self) -> anyhow::Result<CliMode> {
-        if self.init {
-            return Ok(CliMode::Init);
+    pub fn config_sources(&self) -> ConfigSources {
+        ConfigSources {
+            path: self
+                .config
+                .clone()
+                .or_else(|| std::env::var_os("MLBV_CONFIG").map(PathBuf::from)),
+            profile: self
+                .profile
+                .clone()
+                .or_else(|| std::env::var("MLBV_PROFILE").ok()),
+            settings: self.set.clone(),
         }
+    }
 
-        // Assume current date if None provided
+    /// The date from --date, --yesterday or --tomorrow, defaulting to today.
+    fn date(&self) -> anyhow::Result<chrono::NaiveDate> {
         let today = Local::now().date_naive();
-        let date = if let Some(game_date) = &self.date {
-            game_date.0
+        if let Some(game_date) = self.date {
+            game_date.start()
         } else if self.yesterday {
-            today - Duration::days(1)
+            Ok(today - Duration::days(1))
         } else if self.tomorrow {
-            today + Duration::days(1)
+            Ok(today + Duration::days(1))
         } else {
-            today
+            Ok(today)
+        }
+    }
+
+    /// First and last day for a schedule: the --date range, or `days` from the
+    /// --date day. `None` when neither was given.
+    fn span(
+        &self,
+        days: Option<i64>,
+    ) -> anyhow::Result<Option<(chrono::NaiveDate, chrono::NaiveDate)>> {
+        match (self.date, days) {
+            (Some(GameDate::Range(..)), Some(_)) => {
+                anyhow::bail!("--days can't be combined with a --date range")
+            }
+            (Some(GameDate::Range(start, end)), None) => Ok(Some((start, end))),
+            (_, Some(days)) => Ok(Some(Self::date_range(self.date()?, days))),
+            (_, None) => Ok(None),
+        }
+    }
+
+    /// Dates from `anchor` to `days` away from it, in order.
+    fn date_range(anchor: chrono::NaiveDate, days: i64) -> (chrono::NaiveDate, chrono::NaiveDate) {
+        let offset_date = anchor + Duration::days(days);
+        (anchor.min(offset_date), anchor.max(offset_date))
+    }
+
+    fn position_group(pitchers: bool, hitters: bool) -> Option<PositionGroup> {
+        if pitchers {
+            Some(PositionGroup::Pitchers)
+        } else if hitters {
+            Some(PositionGroup::Hitters)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_mode(&self) -> anyhow::Result<CliMode> {
+        let mode = match &self.command {
+            Some(command) => self.command_mode(command)?,
+            None => self.flag_mode()?,
         };
 
+        let is_range = matches!(self.date, Some(GameDate::Range(..)));
+        if is_range
+            && !matches!(
+                mode,
+                CliMode::RangeSchedule { .. } | CliMode::Calendar { .. }
+            )
+        {
+            anyhow::bail!("A --date range only applies to schedules and calendar exports");
+        }
+        if let CliMode::PlayStream { date, .. }
+        | CliMode::PlayCondensedGame { date, .. }
+        | CliMode::PlayRecap { date, .. } = mode
+        {
+            dates::check_archived(date)?;
+        }
+        Ok(mode)
+    }
+
+    /// The first flag given that picks a mode on its own. These belong to the flag
+    /// style and can't be mixed with a subcommand; global flags like --date can.
+    fn mode_flag(&self) -> Option<&'static str> {
+        [
+            (self.init, "--init"),
+            (self.team.is_some(), "--team"),
+            (self.feed.is_some(), "--feed"),
+            (self.audio, "--audio"),
+            (self.condensed, "--condensed"),
+            (self.recap, "--recap"),
+            (self.info, "--info"),
+            (self.plays.is_some(), "--plays"),
+            (self.pitches, "--pitches"),
+            (self.player.is_some(), "--player"),
+            (self.roster.is_some(), "--roster"),
+            (self.pitchers, "--pitchers"),
+            (self.hitters, "--hitters"),
+            (self.days.is_some(), "--days"),
+            (self.watch.is_some(), "--watch"),
+            (self.tui, "--tui"),
+            (self.ics.is_some(), "--ics"),
+            (self.serve_ics.is_some(), "--serve-ics"),
+        ]
+        .into_iter()
+        .find_map(|(set, flag)| set.then_some(flag))
+    }
+
+    fn command_mode(&self, command: &Command) -> anyhow::Result<CliMode> {
+        if let Some(flag) = self.mode_flag() {
+            anyhow::bail!("{flag} can't be combined with a subcommand");
+        }
+
+        let date = self.date()?;
+        let filter = self.filter.clone();
+        let game_number = self.game_number;
+
+        let mode = match command {
+            Command::Play { team, feed, audio } => CliMode::PlayStream {
+                team_code: *team,
+                date,
+                media_type: match audio {
+                    true => MediaType::Audio,
+                    false => MediaType::Video,
+                },
+                feed_type: *feed,
+                game_number,
+            },
+            Command::Condensed { team } => CliMode::PlayCondensedGame {
+                team_code: *team,
+                date,
+                game_number,
+            },
+            Command::Recap { team } => CliMode::PlayRecap {
+                date,
+                team_code: *team,
+                game_number,
+                filter,
+            },
+            Command::Schedule { days } => match self.span(*days)? {
+                Some((start_date, end_date)) => CliMode::RangeSchedule {
+                    start_date,
+                    end_date,
+                    filter,
+                },
+                None => CliMode::DaySchedule { date, filter },
+            },
+            Command::Watch { seconds } => CliMode::WatchSchedule {
+                date,
+                filter,
+                interval_secs: *seconds,
+            },
+            Command::Tui => CliMode::Browse { date, filter },
+            Command::Info { team } => CliMode::Info {
+                date,
+                team_code: *team,
+                game_number,
+                filter,
+            },
+            Command::Plays {
+                team,
+                which,
+                pitches,
+            } => CliMode::Plays {
+                team_code: *team,
+                date,
+                game_number,
+                filter: *which,
+                pitches: *pitches,
+            },
+            Command::Player { name, games } => CliMode::PlayerStats {
+                name: name.join(" "),
+                season: date.year(),
+                games: *games,
+            },
+            Command::Roster {
+                team,
+                kind,
+                pitchers,
+                hitters,
+            } => CliMode::Roster {
+                team_code: *team,
+                date,
+                roster_type: *kind,
+                position_group: Self::position_group(*pitchers, *hitters),
+            },
+            Command::Standings => CliMode::Standings { date },
+            Command::Calendar {
+                serve: Some(addr),
+                days,
+                ..
+            } => CliMode::ServeCalendar {
+                addr: *addr,
+                days: days.unwrap_or(CALENDAR_DAYS),
+                filter,
+            },
+            Command::Calendar { file, days, .. } => {
+                let (start_date, end_date) = self
+                    .span(*days)?
+                    .unwrap_or_else(|| Self::date_range(date, CALENDAR_DAYS - 1));
+                CliMode::Calendar {
+                    start_date,
+                    end_date,
+                    filter,
+                    path: file.clone(),
+                }
+            }
+            Command::Config { action } => match action {
+                ConfigAction::Check => CliMode::ConfigCheck,
+                ConfigAction::Set { key, value } => CliMode::ConfigSet {
+                    key: key.clone(),
+                    value: value.clone(),
+                },
+                ConfigAction::Get { key } => CliMode::ConfigGet { key: key.clone() },
+                ConfigAction::Unset { key } => CliMode::ConfigUnset { key: key.clone() },
+                ConfigAction::List => CliMode::ConfigList,
+            },
+            Command::Completions { shell } => CliMode::Completions { shell: *shell },
+            Command::Man => CliMode::ManPage,
+        };
+
+        // The flag style rejects these combinations while parsing.
+        let machine_output = matches!(
+            mode,
+            CliMode::PlayStream { .. }
+                | CliMode::PlayCondensedGame { .. }
+                | CliMode::PlayRecap { .. }
+                | CliMode::DaySchedule { .. }
+                | CliMode::RangeSchedule { .. }
+        );
+        if self.output != OutputFormat::Table && !machine_output {
+            anyhow::bail!("--output only applies to schedules and stream URLs");
+        }
+        Ok(mode)
+    }
+
+    /// Infer the mode from the original flag style, e.g. `--team wsh --condensed`.
+    fn flag_mode(&self) -> anyhow::Result<CliMode> {
+        if self.init {
+            return Ok(CliMode::Init);
+        }
+
+        let date = self.date()?;
+
         // Assume users want video broadcast unless they opt for Audio specifically
         let media_type = if self.audio {
             MediaType::Audio
@@ -195,6 +885,23 @@ impl Cli {
             MediaType::Video
         };
 
+        if let Some(name) = &self.player {
+            return Ok(CliMode::PlayerStats {
+                name: name.clone(),
+                season: date.year(),
+                games: self.games,
+            });
+        }
+
+        if self.info {
+            return Ok(CliMode::Info {
+                date,
+                team_code: self.team,
+                game_number: self.game_number,
+                filter: self.filter.clone(),
+            });
+        }
+
         if self.recap {
             return Ok(CliMode::PlayRecap {
                 date,
@@ -205,6 +912,23 @@ impl Cli {
         }
 
         if let Some(team_code) = self.team {
+            if let Some(roster_type) = self.roster {
+                return Ok(CliMode::Roster {
+                    team_code,
+                    date,
+                    roster_type,
+                    position_group: Self::position_group(self.pitchers, self.hitters),
+                });
+            }
+            if let Some(filter) = self.plays {
+                return Ok(CliMode::Plays {
+                    team_code,
+                    date,
+                    game_number: self.game_number,
+                    filter,
+                    pitches: self.pitches,
+                });
+            }
             if self.condensed {
                 return Ok(CliMode::PlayCondensedGame {
                     team_code,
@@ -221,12 +945,28 @@ impl Cli {
             });
         }
 
-        if let Some(days) = self.days {
-            let offset_date = today + chrono::Duration::days(days);
-            let (start_date, end_date) = (
-                std::cmp::min(today, offset_date),
-                std::cmp::max(today, offset_date),
-            );
+        if let Some(addr) = self.serve_ics {
+            return Ok(CliMode::ServeCalendar {
+                addr,
+                days: self.days.unwrap_or(CALENDAR_DAYS),
+                filter: self.filter.clone(),
+            });
+        }
+
+        if let Some(path) = &self.ics {
+            let (start_date, end_date) = self
+                .span(self.days)?
+                .unwrap_or_else(|| Self::date_range(date, CALENDAR_DAYS - 1));
+            return Ok(CliMode::Calendar {
+                start_date,
+                end_date,
+                filter: self.filter.clone(),
+                // "-" means stdout.
+                path: (path.as_os_str() != "-").then(|| path.clone()),
+            });
+        }
+
+        if let Some((start_date, end_date)) = self.span(self.days)? {
             return Ok(CliMode::RangeSchedule {
                 start_date,
                 end_date,
@@ -234,6 +974,21 @@ impl Cli {
             });
         }
 
+        if self.tui {
+            return Ok(CliMode::Browse {
+                date,
+                filter: self.filter.clone(),
+            });
+        }
+
+        if let Some(interval_secs) = self.watch {
+            return Ok(CliMode::WatchSchedule {
+                date,
+                filter: self.filter.clone(),
+                interval_secs,
+            });
+        }
+
         Ok(CliMode::DaySchedule {
             date,
             filter: self.filter.clone(),
@@ -0,0 +1,129 @@
+use crate::{
+    api::stats::schedule::{Article, GameData},
+    cli::{display::Charset, spoilers::SpoilerPolicy},
+};
+use regex::Regex;
+
+/// Convert an article's HTML body into plain-text paragraphs.
+fn html_to_paragraphs(html: &str) -> Vec<String> {
+    let breaks = Regex::new(r"(?i)</p>|<br\s*/?>|</h\d>|</li>").expect("valid regex");
+    let tags = Regex::new(r"<[^>]*>").expect("valid regex");
+
+    breaks
+        .split(html)
+        .map(|chunk| {
+            let text = tags.replace_all(chunk, "");
+            let text = decode_entities(&text);
+            text.split_whitespace().collect::<Vec<_>>().join(" ")
+        })
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+fn decode_entities(text: &str) -> String {
+    [
+        ("&nbsp;", " "),
+        ("&quot;", "\""),
+        ("&#39;", "'"),
+        ("&#x27;", "'"),
+        ("&rsquo;", "'"),
+        ("&lsquo;", "'"),
+        ("&ldquo;", "\""),
+        ("&rdquo;", "\""),
+        ("&mdash;", "-"),
+        ("&ndash;", "-"),
+        ("&hellip;", "..."),
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&amp;", "&"), // Must be last so "&amp;lt;" doesn't decode twice.
+    ]
+    .iter()
+    .fold(text.to_string(), |acc, (entity, plain)| {
+        acc.replace(entity, plain)
+    })
+}
+
+fn format_article(label: &str, article: &Article, full_articles: bool, width: usize) -> String {
+    let mut sections = Vec::new();
+
+    if let Some(headline) = &article.headline {
+        sections.push(textwrap::fill(&format!("{label}: {headline}"), width));
+    }
+    if let Some(subhead) = &article.subhead {
+        sections.push(textwrap::fill(subhead, width));
+    }
+
+    let paragraphs = match (full_articles, &article.body, &article.blurb) {
+        (true, Some(body), _) => html_to_paragraphs(body),
+        (_, _, Some(blurb)) => html_to_paragraphs(blurb),
+        _ => Vec::new(),
+    };
+    sections.extend(paragraphs.iter().map(|p| textwrap::fill(p, width)));
+
+    sections.join("\n\n")
+}
+
+/// Render the preview (before/during a game) or recap (after it) for a single game.
+pub fn format_game_info(
+    game: &GameData,
+    full_articles: bool,
+    width: usize,
+    spoilers: &SpoilerPolicy,
+    charset: Charset,
+) -> String {
+    let reveal = spoilers.reveals(game);
+    // Detailed states like "Final: Rain" or "Completed Early" hint at how the game went.
+    let state = match reveal || game.status.abstract_game_state == "Preview" {
+        true => &game.status.detailed_state,
+        false => &game.status.abstract_game_state,
+    };
+    let title = format!(
+        "{} at {} ({state})",
+        game.teams.away.team.name, game.teams.home.team.name
+    );
+    let rule_char = match charset {
+        Charset::Unicode => "─",
+        Charset::Ascii => "-",
+    };
+    let rule = rule_char.repeat(title.chars().count().min(width));
+
+    let body = if game.status.abstract_game_state == "Final" {
+        match game.recap_article() {
+            _ if !reveal => "Recap hidden to avoid spoilers; use --scores to show it.".to_string(),
+            Some(article) => format_article("Recap", article, full_articles, width),
+            None => "No recap available yet.".to_string(),
+        }
+    } else {
+        match game.preview_article() {
+            Some(article) => format_article("Preview", article, full_articles, width),
+            None => "No preview available.".to_string(),
+        }
+    };
+
+    format!("{title}\n{rule}\n{body}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_to_paragraphs_splits_and_strips_tags() {
+        let html = "<p>First <b>bold</b> line.</p><p>Second&nbsp;line &amp; more</p>";
+        assert_eq!(
+            html_to_paragraphs(html),
+            vec!["First bold line.", "Second line & more"]
+        );
+    }
+
+    #[test]
+    fn html_to_paragraphs_handles_breaks_and_empty_chunks() {
+        let html = "One<br/>Two<br>  <p></p>Three";
+        assert_eq!(html_to_paragraphs(html), vec!["One", "Two", "Three"]);
+    }
+
+    #[test]
+    fn decode_entities_does_not_double_decode() {
+        assert_eq!(decode_entities("&amp;lt;"), "&lt;");
+    }
+}
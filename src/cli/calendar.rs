@@ -0,0 +1,249 @@
+use crate::api::{
+    session::MlbSession,
+    stats::schedule::{DaySchedule, GameData, ScheduleFilter},
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, Utc};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time;
+
+const ICS_DATETIME: &str = "%Y%m%dT%H%M%SZ";
+const GAME_LENGTH_HOURS: i64 = 3;
+/// How long a client has to send its request line.
+const READ_TIMEOUT: StdDuration = StdDuration::from_secs(10);
+/// How long one request may take overall, including the schedule fetch.
+const CONNECTION_TIMEOUT: StdDuration = StdDuration::from_secs(60);
+
+/// Escape TEXT values per RFC 5545 section 3.3.11.
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold content lines longer than 75 octets onto continuation lines that start with a space.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if octets + len > 75 {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += len;
+    }
+    folded
+}
+
+fn format_event(game: &GameData, stamp: &str) -> Option<Vec<String>> {
+    let start = DateTime::parse_from_rfc3339(&game.game_date)
+        .ok()?
+        .with_timezone(&Utc);
+    let end = start + Duration::hours(GAME_LENGTH_HOURS);
+
+    let mut description = Vec::new();
+    let tv = game.streaming_feeds(true);
+    if !tv.is_empty() {
+        description.push(format!("TV: {}", tv.join(", ")));
+    }
+    let radio = game.streaming_feeds(false);
+    if !radio.is_empty() {
+        description.push(format!("Radio: {}", radio.join(", ")));
+    }
+
+    let status = match game.status.detailed_state.as_str() {
+        "Cancelled" => "CANCELLED",
+        _ => "CONFIRMED",
+    };
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@mlbv-rs", game.game_pk),
+        format!("DTSTAMP:{stamp}"),
+        format!("DTSTART:{}", start.format(ICS_DATETIME)),
+        format!("DTEND:{}", end.format(ICS_DATETIME)),
+        format!(
+            "SUMMARY:{}",
+            escape_text(&format!(
+                "{} at {}",
+                game.teams.away.team.name, game.teams.home.team.name
+            ))
+        ),
+        format!("STATUS:{status}"),
+    ];
+    if let Some(venue) = &game.venue {
+        lines.push(format!("LOCATION:{}", escape_text(&venue.name)));
+    }
+    if !description.is_empty() {
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape_text(&description.join("\n"))
+        ));
+    }
+    lines.push("END:VEVENT".to_string());
+
+    Some(lines)
+}
+
+/// Build an iCalendar document with one VEVENT per game, keyed by `game_pk`.
+pub fn build_calendar(schedules: &[DaySchedule], generated: DateTime<Utc>) -> String {
+    let stamp = generated.format(ICS_DATETIME).to_string();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//mlbv-rs//MLB Schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "X-WR-CALNAME:MLB Schedule".to_string(),
+    ];
+
+    // Postponed games reappear with the same game_pk on their makeup date; listing both
+    // would give two events the same UID.
+    let events = schedules
+        .iter()
+        .flat_map(|day| &day.games)
+        .filter(|game| game.status.detailed_state != "Postponed")
+        .filter_map(|game| format_event(game, &stamp));
+    for event in events {
+        lines.extend(event);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+/// Fetch games from `days` relative to today and render them as a calendar.
+async fn calendar_from_today<State>(
+    session: &MlbSession<State>,
+    days: i64,
+    filter: Option<&ScheduleFilter>,
+) -> Result<String> {
+    let today = Local::now().date_naive();
+    let offset_date = today + Duration::days(days);
+    let (start_date, end_date) = (today.min(offset_date), today.max(offset_date));
+
+    let schedules = session
+        .fetch_schedule_by_range(&start_date, &end_date, filter)
+        .await?
+        .unwrap_or_default();
+    Ok(build_calendar(&schedules, Utc::now()))
+}
+
+fn http_response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Cache-Control: no-cache\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Serve a calendar subscription feed, regenerated from the Stats API on every request.
+/// Each client is handled on its own task, so a slow or idle one doesn't hold up the rest.
+pub async fn serve_calendar<State: Send + Sync + 'static>(
+    session: MlbSession<State>,
+    addr: SocketAddr,
+    days: i64,
+    filter: Option<ScheduleFilter>,
+) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind calendar server to {addr}"))?;
+    println!("Serving calendar at http://{addr}/mlb.ics (Ctrl-C to stop)");
+
+    let session = Arc::new(session);
+    let filter = Arc::new(filter);
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let session = Arc::clone(&session);
+        let filter = Arc::clone(&filter);
+        tokio::spawn(async move {
+            let served = time::timeout(
+                CONNECTION_TIMEOUT,
+                serve_connection(stream, peer, &session, days, filter.as_ref().as_ref()),
+            );
+            if served.await.is_err() {
+                tracing::warn!("Timed out serving {peer}");
+            }
+        });
+    }
+}
+
+async fn serve_connection<State>(
+    mut stream: TcpStream,
+    peer: SocketAddr,
+    session: &MlbSession<State>,
+    days: i64,
+    filter: Option<&ScheduleFilter>,
+) {
+    let mut buf = [0u8; 4096];
+    let n = match time::timeout(READ_TIMEOUT, stream.read(&mut buf)).await {
+        Ok(Ok(n)) => n,
+        Ok(Err(e)) => {
+            tracing::warn!("Failed to read request from {peer}: {e}");
+            return;
+        }
+        Err(_) => {
+            tracing::warn!("No request from {peer} within {}s", READ_TIMEOUT.as_secs());
+            return;
+        }
+    };
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let request_line = request.lines().next().unwrap_or_default();
+    tracing::info!("{peer} {request_line}");
+
+    let response = if !request_line.starts_with("GET ") {
+        http_response(
+            "405 Method Not Allowed",
+            "text/plain",
+            "Only GET is supported\n",
+        )
+    } else {
+        match calendar_from_today(session, days, filter).await {
+            Ok(calendar) => http_response("200 OK", "text/calendar; charset=utf-8", &calendar),
+            Err(e) => {
+                tracing::warn!("Failed to build calendar: {e:#}");
+                http_response("502 Bad Gateway", "text/plain", "Schedule unavailable\n")
+            }
+        }
+    };
+
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        tracing::warn!("Failed to send calendar to {peer}: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_text_escapes_special_characters() {
+        assert_eq!(
+            escape_text("Nationals Park, DC; TV\\Radio\nHome"),
+            "Nationals Park\\, DC\\; TV\\\\Radio\\nHome"
+        );
+    }
+
+    #[test]
+    fn fold_line_wraps_at_75_octets() {
+        let line = "x".repeat(160);
+        let folded = fold_line(&line);
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].len(), 75);
+        assert!(parts[1].starts_with(' ') && parts[1].len() == 75);
+    }
+
+    #[test]
+    fn fold_line_leaves_short_lines_alone() {
+        assert_eq!(fold_line("VERSION:2.0"), "VERSION:2.0");
+    }
+}
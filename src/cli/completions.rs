@@ -0,0 +1,167 @@
+use crate::api::mediagateway::streams::FeedType;
+use crate::api::stats::schedule::ScheduleFilter;
+use crate::cli::Cli;
+use crate::data::teamdata::{TEAMS, TeamCode};
+use clap::builder::{PossibleValue, StringValueParser, TypedValueParser};
+use clap::{Arg, CommandFactory};
+use clap_complete::Shell;
+use std::ffi::OsStr;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+const BIN_NAME: &str = "mlbv";
+
+const LEAGUES: &[(&str, &str)] = &[("al", "American League"), ("nl", "National League")];
+
+const DIVISIONS: &[(&str, &str, &str)] = &[
+    ("ale", "al_east", "AL East"),
+    ("alc", "al_central", "AL Central"),
+    ("alw", "al_west", "AL West"),
+    ("nle", "nl_east", "NL East"),
+    ("nlc", "nl_central", "NL Central"),
+    ("nlw", "nl_west", "NL West"),
+];
+
+const FEEDS: &[(&str, &str)] = &[
+    ("home", "Home team broadcast"),
+    ("away", "Away team broadcast"),
+    ("national", "National broadcast"),
+];
+
+/// Which values an argument suggests to shell completions and the man page.
+#[derive(Clone, Copy)]
+enum Hints {
+    Teams,
+    Feeds,
+    Filters,
+}
+
+impl Hints {
+    fn values(self) -> Vec<PossibleValue> {
+        let teams = || {
+            TEAMS.iter().map(|team| {
+                PossibleValue::new(team.code.to_string().to_lowercase()).help(team.name)
+            })
+        };
+        let pairs = |pairs: &'static [(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|&(value, help)| PossibleValue::new(value).help(help))
+        };
+        match self {
+            Hints::Teams => teams().collect(),
+            Hints::Feeds => pairs(FEEDS).collect(),
+            Hints::Filters => {
+                pairs(LEAGUES)
+                    .chain(DIVISIONS.iter().map(|&(value, alias, help)| {
+                        PossibleValue::new(value).alias(alias).help(help)
+                    }))
+                    .chain(teams())
+                    .chain([PossibleValue::new("favs").help("Favorite teams from the config")])
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Parses with `T`'s `FromStr`, like clap's default, while listing `hints` as the
+/// values to complete. The arguments hide them from `--help`, where the team list
+/// would drown everything else out.
+#[derive(Clone)]
+pub struct HintedParser<T> {
+    hints: Hints,
+    parse: fn(&str) -> anyhow::Result<T>,
+}
+
+impl<T: FromStr<Err = anyhow::Error>> HintedParser<T> {
+    fn new(hints: Hints) -> Self {
+        Self {
+            hints,
+            parse: T::from_str,
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> TypedValueParser for HintedParser<T> {
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<T, clap::Error> {
+        let parse = self.parse;
+        StringValueParser::new()
+            .try_map(move |s| parse(&s).map_err(Box::<dyn std::error::Error + Send + Sync>::from))
+            .parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(self.hints.values().into_iter()))
+    }
+}
+
+pub fn teams() -> HintedParser<TeamCode> {
+    HintedParser::new(Hints::Teams)
+}
+
+pub fn feeds() -> HintedParser<FeedType> {
+    HintedParser::new(Hints::Feeds)
+}
+
+pub fn filters() -> HintedParser<ScheduleFilter> {
+    HintedParser::new(Hints::Filters)
+}
+
+/// Write a completion script for `shell`.
+pub fn write_completions(shell: Shell, out: &mut impl Write) {
+    clap_complete::generate(shell, &mut Cli::command(), BIN_NAME, out);
+}
+
+/// Write the man page, in roff.
+pub fn write_man_page(out: &mut impl Write) -> io::Result<()> {
+    clap_mangen::Man::new(Cli::command().name(BIN_NAME)).render(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(write: impl FnOnce(&mut Vec<u8>)) -> String {
+        let mut out = Vec::new();
+        write(&mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn completions_suggest_teams_divisions_and_feeds() {
+        let bash = render(|out| write_completions(Shell::Bash, out));
+        for value in ["wsh", "nlw", "ale", "favs", "national"] {
+            assert!(
+                bash.contains(value),
+                "{value} missing from bash completions"
+            );
+        }
+
+        let zsh = render(|out| write_completions(Shell::Zsh, out));
+        assert!(zsh.contains(r#"wsh\:"Washington Nationals""#));
+    }
+
+    #[test]
+    fn man_page_documents_subcommands() {
+        let man = render(|out| write_man_page(out).unwrap());
+        assert!(man.starts_with(".ie"), "not roff: {}", &man[..20]);
+        assert!(man.contains(".TH mlbv"));
+        assert!(man.contains("standings"));
+    }
+
+    #[test]
+    fn hinted_parsers_keep_the_type_errors() {
+        let cmd = clap::Command::new("mlbv");
+        let parser = teams();
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("OAK")).is_ok());
+        let err = parser.parse_ref(&cmd, None, OsStr::new("xyz")).unwrap_err();
+        assert!(err.to_string().contains("Invalid team code: xyz"));
+    }
+}
@@ -0,0 +1,284 @@
+use crate::api::session::MlbSession;
+use anyhow::{Context, Result};
+use chrono::{Datelike, Local, NaiveDate, TimeDelta, Weekday};
+use std::str::FromStr;
+
+/// MLB.tv archives start with the 2022 season.
+const ARCHIVE_START_YEAR: i32 = 2022;
+
+/// Opening Day as the first full slate of games. The API's start date also counts a
+/// season's international series, which can come a week or two earlier, so it's only
+/// asked about seasons after these.
+const OPENING_DAYS: &[(i32, u32, u32)] = &[
+    (2022, 4, 7),
+    (2023, 3, 30),
+    (2024, 3, 28),
+    (2025, 3, 27),
+    (2026, 3, 26),
+];
+
+/// A day, or an inclusive range of days, given to --date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameDate {
+    Day(NaiveDate),
+    Range(NaiveDate, NaiveDate),
+    /// `opening-day` for a season after the built-in table, until `resolve` looks up
+    /// its date.
+    OpeningDay(i32),
+}
+
+impl GameDate {
+    /// Parse relative to `today`, e.g. `-3`, `last friday`, `july 4` or
+    /// `2024-09-01..2024-09-07`.
+    pub fn parse(s: &str, today: NaiveDate) -> Result<Self> {
+        let Some((start, end)) = s.split_once("..") else {
+            if let Some(season) = opening_season(s, today)? {
+                return Ok(
+                    known_opening_day(season).map_or(GameDate::OpeningDay(season), GameDate::Day)
+                );
+            }
+            return parse_day(s, today).map(GameDate::Day);
+        };
+        let start = parse_day(start, today)?;
+        let end = parse_day(end, today)?;
+        if end < start {
+            anyhow::bail!("Date range '{s}' ends before it starts");
+        }
+        Ok(GameDate::Range(start, end))
+    }
+
+    /// The day, or the first day of a range. An Opening Day that `resolve` couldn't
+    /// look up is an error.
+    pub fn start(self) -> Result<NaiveDate> {
+        match self {
+            GameDate::Day(date) | GameDate::Range(date, _) => Ok(date),
+            GameDate::OpeningDay(season) => known_opening_day(season),
+        }
+    }
+
+    /// Look up Opening Day from the stats API for seasons after the built-in table.
+    /// Other dates are already known.
+    pub async fn resolve<State>(self, session: &MlbSession<State>) -> Self {
+        let GameDate::OpeningDay(season) = self else {
+            return self;
+        };
+        match session.fetch_regular_season_start(season).await {
+            Ok(Some(date)) => GameDate::Day(date),
+            Ok(None) => self,
+            Err(e) => {
+                tracing::warn!("Failed to look up Opening Day {season}: {e:#}");
+                self
+            }
+        }
+    }
+}
+
+impl FromStr for GameDate {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Local::now().date_naive())
+    }
+}
+
+/// Playback needs the game in the MLB.tv archive; schedules and stats go back further.
+pub fn check_archived(date: NaiveDate) -> Result<()> {
+    if date.year() < ARCHIVE_START_YEAR {
+        anyhow::bail!("MLB.tv archives only go back to the start of {ARCHIVE_START_YEAR}.");
+    }
+    Ok(())
+}
+
+fn parse_day(s: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let s = s.trim().to_lowercase();
+
+    // Try to parse a few common date formats before the relative ones.
+    let formats = ["%Y-%m-%d", "%m-%d-%Y", "%m/%d/%Y"];
+    for fmt in formats {
+        if let Ok(date) = NaiveDate::parse_from_str(&s, fmt) {
+            return Ok(date);
+        }
+    }
+
+    let date = match s.as_str() {
+        "today" => Some(today),
+        "yesterday" => today.pred_opt(),
+        "tomorrow" => today.succ_opt(),
+        _ if s.starts_with(['+', '-']) => {
+            let days = s.parse::<i64>().ok();
+            days.and_then(TimeDelta::try_days)
+                .and_then(|days| today.checked_add_signed(days))
+        }
+        _ if s.starts_with("opening") => {
+            let season = opening_season(&s, today)?.unwrap_or(today.year());
+            return known_opening_day(season);
+        }
+        _ => weekday(&s, today).or_else(|| month_day(&s, today)),
+    };
+    date.with_context(|| {
+        format!(
+            "Invalid date: '{s}'; expected YYYY-MM-DD, -3, +2, yesterday, last friday, \
+             july 4, opening-day or START..END"
+        )
+    })
+}
+
+/// `friday` is the latest Friday up to today, `last friday` the one before today
+/// and `next friday` the one after.
+fn weekday(s: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (which, day) = match s.split_once(' ') {
+        Some((which @ ("last" | "next"), day)) => (which, day.trim()),
+        _ => ("", s),
+    };
+    let day = day.parse::<Weekday>().ok()?;
+    let back = (7 + today.weekday().num_days_from_monday() - day.num_days_from_monday()) % 7;
+    let days = match which {
+        "last" if back == 0 => -7,
+        "next" => 7 - back as i64,
+        _ => -(back as i64),
+    };
+    today.checked_add_signed(TimeDelta::days(days))
+}
+
+/// `july 4`, `jul 4` or `july 4, 2024`, in the current year unless one is given.
+fn month_day(s: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let s = s.replace(',', " ");
+    let with_year = match s.split_whitespace().count() {
+        2 => format!("{s} {}", today.year()),
+        _ => s,
+    };
+    NaiveDate::parse_from_str(&with_year, "%B %d %Y").ok()
+}
+
+/// The season of `opening-day` (this one) or `opening-day-2024`, or `None` for
+/// other dates.
+fn opening_season(s: &str, today: NaiveDate) -> Result<Option<i32>> {
+    let s = s.trim().to_lowercase();
+    if !s.starts_with("opening") {
+        return Ok(None);
+    }
+    let rest = s
+        .strip_prefix("opening-day")
+        .or_else(|| s.strip_prefix("opening day"))
+        .with_context(|| {
+            format!("Invalid date: '{s}'; expected opening-day or opening-day-YYYY")
+        })?;
+    let season = match rest.trim_start_matches(['-', ' ']) {
+        "" => today.year(),
+        year => year
+            .parse()
+            .with_context(|| format!("Invalid season in '{s}'"))?,
+    };
+    Ok(Some(season))
+}
+
+fn known_opening_day(season: i32) -> Result<NaiveDate> {
+    OPENING_DAYS
+        .iter()
+        .find(|&&(year, ..)| year == season)
+        .and_then(|&(_, month, day)| NaiveDate::from_ymd_opt(season, month, day))
+        .with_context(|| format!("Opening Day {season} isn't known; give its date instead"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Wednesday.
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 7, 16).unwrap()
+    }
+
+    fn day(s: &str) -> String {
+        match GameDate::parse(s, today()).unwrap() {
+            GameDate::Day(date) => date.to_string(),
+            other => panic!("{s} parsed as {other:?}"),
+        }
+    }
+
+    #[test]
+    fn game_date_from_str_parses_valid_formats() {
+        assert!(GameDate::from_str("2024-10-01").is_ok());
+        assert!(GameDate::from_str("10-01-2024").is_ok());
+        assert!(GameDate::from_str("10/01/2024").is_ok());
+    }
+
+    #[test]
+    fn game_date_from_str_rejects_invalid_format() {
+        let result = GameDate::from_str("invalid-date");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_relative_days_and_weekdays() {
+        assert_eq!(day("-3"), "2025-07-13");
+        assert_eq!(day("+2"), "2025-07-18");
+        assert_eq!(day("Yesterday"), "2025-07-15");
+        assert_eq!(day("wednesday"), "2025-07-16");
+        assert_eq!(day("last wednesday"), "2025-07-09");
+        assert_eq!(day("last friday"), "2025-07-11");
+        assert_eq!(day("next fri"), "2025-07-18");
+        assert!(GameDate::parse("-99999999999999", today()).is_err());
+    }
+
+    #[test]
+    fn parses_month_days_and_opening_day() {
+        assert_eq!(day("july 4"), "2025-07-04");
+        assert_eq!(day("Sep 28, 2024"), "2024-09-28");
+        assert!(GameDate::parse("july 32", today()).is_err());
+    }
+
+    #[test]
+    fn opening_day_uses_the_table_for_its_seasons() {
+        let opening_day = |s| GameDate::parse(s, today()).unwrap();
+        assert_eq!(day("opening-day"), "2025-03-27");
+        assert_eq!(day("opening day 2024"), "2024-03-28");
+        assert_eq!(opening_day("Opening Day 2030"), GameDate::OpeningDay(2030));
+        assert!(opening_day("opening-day-2030").start().is_err());
+        assert!(opening_day("opening-day-1999").start().is_err());
+        assert!(GameDate::parse("opening-week", today()).is_err());
+
+        // Ranges are parsed offline, so only the table's seasons work there.
+        assert!(GameDate::parse("opening-day-2030..2030-05-01", today()).is_err());
+    }
+
+    #[tokio::test]
+    async fn opening_day_agrees_online_offline_and_in_ranges() {
+        let session = MlbSession::new().unwrap();
+        for &(season, ..) in OPENING_DAYS {
+            let day = GameDate::parse(&format!("opening-day-{season}"), today()).unwrap();
+            let range =
+                GameDate::parse(&format!("opening-day-{season}..{season}-10-01"), today()).unwrap();
+            // Table seasons never reach the API, so this holds without a network too.
+            let resolved = day.resolve(&session).await;
+            assert_eq!(resolved, day);
+            assert_eq!(resolved.start().unwrap(), range.start().unwrap());
+        }
+    }
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(
+            GameDate::parse("2024-09-01..2024-09-07", today()).unwrap(),
+            GameDate::Range(
+                NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 9, 7).unwrap()
+            )
+        );
+        assert_eq!(
+            GameDate::parse("-3..today", today())
+                .unwrap()
+                .start()
+                .unwrap(),
+            NaiveDate::from_ymd_opt(2025, 7, 13).unwrap()
+        );
+        assert!(GameDate::parse("2024-09-07..2024-09-01", today()).is_err());
+    }
+
+    #[test]
+    fn only_playback_needs_the_archive() {
+        let old = GameDate::from_str("2021-10-01").unwrap();
+        assert!(check_archived(old.start().unwrap()).is_err());
+        assert!(check_archived(today()).is_ok());
+    }
+}
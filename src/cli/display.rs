@@ -1,11 +1,17 @@
 use crate::{
-    api::stats::schedule::{DaySchedule, GameData},
-    config::AppConfig,
-    data::teamdata::Team,
+    api::stats::schedule::{DaySchedule, GameData, ProbablePitcher},
+    cli::spoilers::SpoilerPolicy,
+    config::{AppConfig, Column, GroupBy, SortOrder, ThemeSetting, TimeFormat, TimeZoneSetting},
+    data::teamdata::{DivisionRegion, League, Team, TeamCode},
 };
 use chrono::{DateTime, Local};
+use std::collections::BTreeMap;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
 use tabled::{
-    Table, Tabled,
+    Table,
+    builder::Builder,
     settings::{
         Alignment, Span, Style, Theme, Width,
         object::{Columns, Rows},
@@ -20,13 +26,16 @@ pub enum DisplayMode {
     Compact,   // < 80 chars
 }
 
+pub fn terminal_width() -> usize {
+    terminal_size::terminal_size()
+        .map(|(w, _)| w.0 as usize)
+        .unwrap_or(80) // Default fallback to 80 columns
+}
+
 impl DisplayMode {
     /// Determine the appropriate display mode based on terminal width
     pub fn from_terminal_width() -> Self {
-        let (width, _) = terminal_size::terminal_size()
-            .map(|(w, h)| (w.0 as usize, h.0 as usize))
-            .unwrap_or((80, 24)); // Default fallback to 80x24
-
+        let width = terminal_width();
         tracing::debug!("Terminal width: {}", width);
 
         if width >= 97 {
@@ -39,47 +48,163 @@ impl DisplayMode {
     }
 }
 
+impl FromStr for DisplayMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(Self::Standard),
+            "condensed" => Ok(Self::Condensed),
+            "compact" => Ok(Self::Compact),
+            _ => {
+                anyhow::bail!("Invalid display mode: {s}; expected standard, condensed or compact")
+            }
+        }
+    }
+}
+
 // Schedule display logic
-#[derive(Tabled)]
 pub struct GameRow {
-    #[tabled(rename = "Matchup")]
     pub matchup: String,
-    #[tabled(rename = "Series")]
     pub series: String,
-    #[tabled(rename = "Score")]
     pub score: String,
-    #[tabled(rename = "State")]
     pub state: String,
-    #[tabled(rename = "Available Feeds")]
     pub feeds: String,
-    #[tabled(rename = "Highlights")]
     pub highlights: String,
+    pub pitchers: String,
+    pub venue: String,
+    pub tv: String,
+    pub records: String,
+    pub weather: String,
+    pub game_pk: u64,
+    /// Away and home team ids, for favorite highlighting.
+    pub team_ids: [u32; 2],
+    pub critical: bool,
+}
+
+impl GameRow {
+    pub fn cell(&self, column: Column) -> &str {
+        match column {
+            Column::Matchup => &self.matchup,
+            Column::Series => &self.series,
+            Column::Score => &self.score,
+            Column::State => &self.state,
+            Column::Feeds => &self.feeds,
+            Column::Highlights => &self.highlights,
+            Column::Pitchers => &self.pitchers,
+            Column::Venue => &self.venue,
+            Column::Tv => &self.tv,
+            Column::Records => &self.records,
+            Column::Weather => &self.weather,
+        }
+    }
+}
+
+pub fn column_header(column: Column) -> &'static str {
+    match column {
+        Column::Matchup => "Matchup",
+        Column::Series => "Series",
+        Column::Score => "Score",
+        Column::State => "State",
+        Column::Feeds => "Available Feeds",
+        Column::Highlights => "Highlights",
+        Column::Pitchers => "Probable Pitchers",
+        Column::Venue => "Venue",
+        Column::Tv => "TV",
+        Column::Records => "Records",
+        Column::Weather => "Weather",
+    }
+}
+
+/// Settings that control what goes into each schedule row.
+pub struct ScheduleOptions {
+    pub display_mode: DisplayMode,
+    pub spoilers: SpoilerPolicy,
+    pub probable_pitchers: bool,
+    pub live_situation: bool,
+    pub critical_inning: u8,
+    pub critical_run_margin: u8,
+    pub time_format: Option<TimeFormat>,
+    pub timezone: TimeZoneSetting,
+    pub columns: Vec<Column>,
+    pub charset: Charset,
+    pub color: bool,
+    pub favorites: Vec<TeamCode>,
+    pub sort: SortOrder,
+    pub group_by: GroupBy,
+    pub favorites_first: bool,
+}
+
+/// A titled part of a day's schedule, rendered as its own table.
+pub struct ScheduleSection {
+    pub title: Option<String>,
+    pub rows: Vec<GameRow>,
 }
 
 pub struct ScheduleTable {
     pub table: Table,
     pub rows: Vec<GameRow>,
+    pub columns: Vec<Column>,
 }
 
-fn schedule_table_theme() -> Theme {
-    let style = Style::modern()
-        .remove_horizontal() // Remove internal horizontal lines
-        .horizontals([(1, HorizontalLine::inherit(Style::modern()))]) // Re-create just the header border
-        .remove_frame();
+/// Characters used for table borders and feed labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+    Unicode,
+    Ascii,
+}
 
-    Theme::from_style(style)
+/// True when stdout isn't a terminal or NO_COLOR is set (https://no-color.org).
+pub fn plain_output() -> bool {
+    !io::stdout().is_terminal() || env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
 }
 
-fn compact_table_theme() -> Theme {
-    let style = Style::modern()
-        .remove_horizontal()
-        .horizontals([(
-            1,
-            HorizontalLine::inherit(Style::modern()).intersection('┬'),
-        )])
-        .remove_frame();
+impl Charset {
+    pub fn resolve(setting: ThemeSetting) -> Self {
+        match setting {
+            ThemeSetting::Unicode => Self::Unicode,
+            ThemeSetting::Ascii => Self::Ascii,
+            ThemeSetting::Auto if plain_output() => Self::Ascii,
+            ThemeSetting::Auto => Self::Unicode,
+        }
+    }
+}
+
+pub fn schedule_table_theme(charset: Charset) -> Theme {
+    match charset {
+        Charset::Unicode => Theme::from_style(
+            Style::modern()
+                .remove_horizontal() // Remove internal horizontal lines
+                .horizontals([(1, HorizontalLine::inherit(Style::modern()))]) // Re-create just the header border
+                .remove_frame(),
+        ),
+        Charset::Ascii => Theme::from_style(
+            Style::ascii()
+                .remove_horizontal()
+                .horizontals([(1, HorizontalLine::inherit(Style::ascii()))])
+                .remove_frame(),
+        ),
+    }
+}
 
-    Theme::from_style(style)
+fn compact_table_theme(charset: Charset) -> Theme {
+    match charset {
+        Charset::Unicode => Theme::from_style(
+            Style::modern()
+                .remove_horizontal()
+                .horizontals([(
+                    1,
+                    HorizontalLine::inherit(Style::modern()).intersection('┬'),
+                )])
+                .remove_frame(),
+        ),
+        Charset::Ascii => Theme::from_style(
+            Style::ascii()
+                .remove_horizontal()
+                .horizontals([(1, HorizontalLine::inherit(Style::ascii()))])
+                .remove_frame(),
+        ),
+    }
 }
 
 fn title_case_feed(feed: &str) -> String {
@@ -90,113 +215,179 @@ fn title_case_feed(feed: &str) -> String {
     }
 }
 
+fn set_column_width(table: &mut Table, idx: usize, column: Column, display_mode: &DisplayMode) {
+    let col = Columns::one(idx);
+    let is_compact = matches!(display_mode, DisplayMode::Compact);
+
+    match (column, display_mode) {
+        (Column::Matchup, DisplayMode::Standard) => {
+            table
+                .modify(col, Width::increase(33)) // Set minimum width for matchup column
+                .modify(col, Width::wrap(33).keep_words(true)); // Wrap to next line if too long
+        }
+        (Column::Matchup, DisplayMode::Condensed) => {
+            table.modify(col, Width::wrap(17));
+        }
+        (Column::Matchup, DisplayMode::Compact) => {}
+        (Column::Series, _) => {
+            table.modify(col, Width::increase(if is_compact { 3 } else { 6 }));
+        }
+        (Column::Score, _) => {
+            table.modify(col, Width::increase(5));
+        }
+        (Column::State, DisplayMode::Compact) => {
+            table.modify(col, Width::wrap(3));
+        }
+        (Column::State, _) => {
+            table.modify(col, Width::wrap(10).keep_words(true));
+        }
+        (Column::Feeds, _) => {
+            table.modify(col, Width::wrap(if is_compact { 7 } else { 16 }));
+        }
+        (Column::Highlights, _) => {
+            table.modify(col, Width::wrap(if is_compact { 3 } else { 10 }));
+        }
+        (Column::Pitchers, DisplayMode::Standard) | (Column::Venue, DisplayMode::Standard) => {
+            table.modify(col, Width::wrap(20).keep_words(true));
+        }
+        (Column::Pitchers, _) | (Column::Venue, _) => {
+            table.modify(
+                col,
+                Width::wrap(if is_compact { 10 } else { 14 }).keep_words(true),
+            );
+        }
+        (Column::Tv, _) => {
+            table.modify(col, Width::wrap(if is_compact { 6 } else { 12 }));
+        }
+        (Column::Records, _) => {}
+        (Column::Weather, _) => {
+            table.modify(
+                col,
+                Width::wrap(if is_compact { 6 } else { 14 }).keep_words(true),
+            );
+        }
+    }
+}
+
 pub fn create_schedule_table(
     rows: Vec<GameRow>,
     header_date_str: &str,
-    display_mode: &DisplayMode,
+    options: &ScheduleOptions,
 ) -> ScheduleTable {
-    let table_theme = schedule_table_theme();
-    let compact_theme = compact_table_theme();
-    let mut table = Table::new(&rows);
-
-    table
-        .modify((0, 0), header_date_str) // Replace matchup header with date + dow
-        .modify(Columns::one(0), Alignment::left()) // Left-align times
-        .modify(Columns::one(1), Alignment::center()) // Center-align series
-        .modify(Columns::one(2), Alignment::center()); // Center-align scores
+    let (display_mode, columns) = (&options.display_mode, options.columns.as_slice());
+    let mut builder = Builder::default();
+    builder.push_record(columns.iter().map(|&column| column_header(column)));
+    for row in &rows {
+        builder.push_record(columns.iter().map(|&column| row.cell(column)));
+    }
+    let mut table = builder.build();
+
+    table.modify((0, 0), header_date_str); // Replace first header with date + dow
+    for (idx, &column) in columns.iter().enumerate() {
+        let alignment = match column {
+            Column::Matchup => Alignment::left(), // Left-align times
+            Column::Series | Column::Score => Alignment::center(),
+            _ => continue,
+        };
+        table.modify(Columns::one(idx), alignment);
+    }
 
     match display_mode {
-        DisplayMode::Standard => {
-            table
-                .with(table_theme)
-                .modify(Columns::one(0), Width::increase(33)) // Set minimum width for matchup column
-                .modify(Columns::one(0), Width::wrap(33).keep_words(true)) // Wrap to next line if too long
-                .modify(Columns::one(1), Width::increase(6)) // Series
-                .modify(Columns::one(2), Width::increase(5)) // Score
-                .modify(Columns::one(3), Width::wrap(10).keep_words(true)) // State
-                .modify(Columns::one(4), Width::wrap(16)) // Feeds
-                .modify(Columns::one(5), Width::wrap(10)); // Highlights
-        }
-        DisplayMode::Condensed => {
-            table
-                .with(table_theme)
-                .modify(Columns::one(0), Width::wrap(17)) // Wrap to next line if too long
-                .modify(Columns::one(1), Width::increase(6)) // Series
-                .modify(Columns::one(2), Width::increase(5)) // Score
-                .modify(Columns::one(3), Width::wrap(10).keep_words(true)) // State
-                .modify(Columns::one(4), Width::wrap(16)) // Feeds
-                .modify(Columns::one(5), Width::wrap(10)); // Highlights
-        }
         DisplayMode::Compact => {
             table
-                .with(compact_theme)
-                .modify(Rows::one(0), Span::column(6)) // Span header across all columns
-                .modify(Columns::one(1), Width::increase(3)) // Series
-                .modify(Columns::one(2), Width::increase(5)) // Score
-                .modify(Columns::one(3), Width::wrap(3)) // State
-                .modify(Columns::one(4), Width::wrap(7)) // Feeds
-                .modify(Columns::one(5), Width::wrap(3)); // Highlights
+                .with(compact_table_theme(options.charset))
+                .modify(Rows::one(0), Span::column(columns.len() as isize)); // Span header across all columns
+        }
+        _ => {
+            table.with(schedule_table_theme(options.charset));
         }
     }
+    for (idx, &column) in columns.iter().enumerate() {
+        set_column_width(&mut table, idx, column, display_mode);
+    }
 
-    ScheduleTable { table, rows }
+    ScheduleTable {
+        table,
+        rows,
+        columns: columns.to_vec(),
+    }
 }
 
-pub fn color_favorite_teams(
+/// Apply favorite-team row colors, then highlight critical games on top of them.
+pub fn color_schedule_table(
     sched_table: ScheduleTable,
     config: &AppConfig,
-    display_mode: &DisplayMode,
+    options: &ScheduleOptions,
 ) -> Table {
     let mut table = sched_table.table;
-    let is_standard = display_mode == &DisplayMode::Standard;
-    let fav_teams = &config.favorites.teams;
-    let fav_color = &config.favorites.color;
-
-    for (idx, row) in sched_table.rows.iter().enumerate() {
-        let row_num = idx + 1;
+    if !options.color {
+        return table;
+    }
+    color_favorite_teams(&mut table, &sched_table.rows, config);
+    color_critical_games(&mut table, &sched_table.rows, &sched_table.columns, config);
+    table
+}
 
-        let matched_team = fav_teams.iter().find_map(|&code| {
-            let team = Team::find_by_code(code);
-            let code_str = code.to_string();
+fn color_favorite_teams(table: &mut Table, rows: &[GameRow], config: &AppConfig) {
+    for (idx, row) in rows.iter().enumerate() {
+        if let Some(color) = config.favorites.color_for(row.team_ids) {
+            table.modify(Rows::one(idx + 1), color);
+        }
+    }
+}
 
-            if (is_standard && row.matchup.contains(team.nickname))
-                || (!is_standard && row.matchup.contains(&code_str))
-            {
-                Some(code)
-            } else {
-                None
-            }
-        });
+/// Color the score and state cells of critical games.
+fn color_critical_games(
+    table: &mut Table,
+    rows: &[GameRow],
+    columns: &[Column],
+    config: &AppConfig,
+) {
+    let critical_columns: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| matches!(column, Column::Score | Column::State))
+        .map(|(idx, _)| idx)
+        .collect();
 
-        if let Some(team_code) = matched_team
-            && let Some(color) = fav_color.to_tabled_color(Some(team_code))
-        {
-            table.modify(Rows::one(row_num), color);
+    for (idx, row) in rows.iter().enumerate().filter(|(_, row)| row.critical) {
+        let Some(color) = config.favorites.critical_color_for(row.team_ids) else {
+            continue;
+        };
+        let row_num = idx + 1;
+        for &col in &critical_columns {
+            table.modify((row_num, col), color.clone());
         }
     }
-
-    table
 }
 
-fn prepare_matchup(game: &GameData, display_mode: &DisplayMode) -> String {
-    let away_team = Team::find_by_id(&game.teams.away.team.id);
-    let home_team = Team::find_by_id(&game.teams.home.team.id);
+fn prepare_game_time(game: &GameData, options: &ScheduleOptions) -> String {
+    let time_format = match (options.time_format, options.display_mode) {
+        (Some(TimeFormat::Hour12), _) => "%I:%M%p",
+        (Some(TimeFormat::Hour24), _) | (None, DisplayMode::Compact) => "%H:%M",
+        (None, _) => "%I:%M%p",
+    };
 
-    let time_format = match display_mode {
-        DisplayMode::Compact => "%H:%M",
-        _ => "%I:%M%p",
+    let Ok(start) = DateTime::parse_from_rfc3339(&game.game_date) else {
+        return "TBD".to_string();
     };
 
-    let game_time = DateTime::parse_from_rfc3339(&game.game_date)
-        .map(|dt| {
-            dt.with_timezone(&Local)
-                .format(time_format)
-                .to_string()
-                .to_lowercase()
-        })
-        .unwrap_or_else(|_| "TBD".to_string());
+    // Fall back to local time when the ballpark's zone wasn't returned.
+    let game_time = match (options.timezone, game.venue_time_zone()) {
+        (TimeZoneSetting::Named(tz), _) | (TimeZoneSetting::Ballpark, Some(tz)) => {
+            start.with_timezone(&tz).format(time_format).to_string()
+        }
+        _ => start.with_timezone(&Local).format(time_format).to_string(),
+    };
+    game_time.to_lowercase()
+}
 
-    match display_mode {
+fn prepare_matchup(game: &GameData, options: &ScheduleOptions) -> String {
+    let away_team = Team::find_by_id(&game.teams.away.team.id);
+    let home_team = Team::find_by_id(&game.teams.home.team.id);
+    let game_time = prepare_game_time(game, options);
+
+    match options.display_mode {
         DisplayMode::Standard => {
             format!(
                 "{} {} at {}",
@@ -212,12 +403,111 @@ fn prepare_matchup(game: &GameData, display_mode: &DisplayMode) -> String {
     }
 }
 
+/// Away and home probable starters for games that haven't started.
+fn prepare_pitchers(game: &GameData, display_mode: &DisplayMode) -> Option<(String, String)> {
+    // Probable starters only matter until first pitch.
+    if game.status.abstract_game_state != "Preview" {
+        return None;
+    }
+
+    let away = game.teams.away.probable_pitcher.as_ref();
+    let home = game.teams.home.probable_pitcher.as_ref();
+    if away.is_none() && home.is_none() {
+        return None;
+    }
+
+    let format_pitcher = |pitcher: Option<&ProbablePitcher>| -> String {
+        let Some(pitcher) = pitcher else {
+            return "TBD".to_string();
+        };
+        match (display_mode, pitcher.season_line()) {
+            (DisplayMode::Standard, Some(line)) => format!(
+                "{} ({}-{}, {})",
+                pitcher.last_name(),
+                line.wins.unwrap_or(0),
+                line.losses.unwrap_or(0),
+                line.era.as_deref().unwrap_or("-.--")
+            ),
+            _ => pitcher.last_name().to_string(),
+        }
+    };
+
+    Some((format_pitcher(away), format_pitcher(home)))
+}
+
+fn prepare_venue(game: &GameData) -> String {
+    game.venue
+        .as_ref()
+        .map(|venue| venue.name.clone())
+        .unwrap_or_default()
+}
+
+fn prepare_tv(game: &GameData, display_mode: &DisplayMode) -> String {
+    let Some(broadcasts) = &game.broadcasts else {
+        return String::new();
+    };
+
+    let mut call_signs: Vec<&str> = broadcasts
+        .iter()
+        .filter(|b| b.kind == "TV" && b.language == "en")
+        .filter_map(|b| b.call_sign.as_deref())
+        .collect();
+    call_signs.sort();
+    call_signs.dedup();
+
+    match display_mode {
+        DisplayMode::Compact => call_signs.join(","),
+        _ => call_signs.join(", "),
+    }
+}
+
+fn prepare_records(game: &GameData, display_mode: &DisplayMode, reveal: bool) -> String {
+    // Records update once a game ends, so they'd give away the result.
+    if !reveal && game.status.abstract_game_state != "Preview" {
+        return String::new();
+    }
+
+    let (Some(away), Some(home)) = (
+        &game.teams.away.league_record,
+        &game.teams.home.league_record,
+    ) else {
+        return String::new();
+    };
+
+    let separator = match display_mode {
+        DisplayMode::Compact => "/",
+        _ => " / ",
+    };
+    format!(
+        "{}-{}{separator}{}-{}",
+        away.wins, away.losses, home.wins, home.losses
+    )
+}
+
+fn prepare_weather(game: &GameData, display_mode: &DisplayMode, charset: Charset) -> String {
+    let Some(weather) = &game.weather else {
+        return String::new();
+    };
+
+    let degrees = match charset {
+        Charset::Unicode => "°",
+        Charset::Ascii => "F",
+    };
+    match (display_mode, &weather.temp, &weather.condition) {
+        (DisplayMode::Compact, Some(temp), _) => format!("{temp}{degrees}"),
+        (_, Some(temp), Some(condition)) => format!("{temp}{degrees} {condition}"),
+        (_, Some(temp), None) => format!("{temp}{degrees}"),
+        (_, None, Some(condition)) => condition.clone(),
+        _ => String::new(),
+    }
+}
+
 fn prepare_series(game: &GameData) -> String {
     format!("{}/{}", &game.series_game_number, &game.games_in_series)
 }
 
-fn prepare_score(game: &GameData, scores: bool) -> String {
-    if !scores {
+fn prepare_score(game: &GameData, reveal: bool) -> String {
+    if !reveal {
         return String::new();
     };
 
@@ -230,16 +520,25 @@ fn prepare_score(game: &GameData, scores: bool) -> String {
     format!("{away_score}-{home_score}")
 }
 
-fn prepare_state(game: &GameData, display_mode: &DisplayMode) -> String {
+fn prepare_state(game: &GameData, display_mode: &DisplayMode, reveal: bool) -> String {
     let status_code = &game.status.status_code;
     let coded_game_state = &game.status.coded_game_state;
     let abstract_state = &game.status.abstract_game_state;
     let detailed_state = &game.status.detailed_state;
 
     let is_compact = matches!(display_mode, DisplayMode::Compact);
-    let is_in_progress = status_code == "I" || matches!(coded_game_state.as_str(), "M" | "N");
 
-    if is_in_progress {
+    // Innings, extra innings and shortened-game reasons all hint at the result.
+    if !reveal && (game.is_in_progress() || abstract_state == "Final") {
+        return match (is_compact, game.is_in_progress()) {
+            (true, true) => "I".to_string(),
+            (true, false) => "F".to_string(),
+            (false, true) => "In Progress".to_string(),
+            (false, false) => "Final".to_string(),
+        };
+    }
+
+    if game.is_in_progress() {
         if let Some(linescore) = &game.linescore
             && let Some(inning) = linescore.current_inning
             && let Some(is_top) = linescore.is_top_inning
@@ -283,7 +582,40 @@ fn prepare_state(game: &GameData, display_mode: &DisplayMode) -> String {
     }
 }
 
-fn prepare_feeds(game: &GameData, display_mode: &DisplayMode) -> String {
+/// Count, outs and runners for in-progress games, shown beneath the inning.
+fn prepare_situation(game: &GameData, display_mode: &DisplayMode) -> Option<String> {
+    if !game.is_in_progress() || matches!(display_mode, DisplayMode::Compact) {
+        return None;
+    }
+
+    let linescore = game.linescore.as_ref()?;
+    let count = format!(
+        "{}-{}, {} out",
+        linescore.balls.unwrap_or(0),
+        linescore.strikes.unwrap_or(0),
+        linescore.outs.unwrap_or(0)
+    );
+
+    let Some(offense) = &linescore.offense else {
+        return Some(count);
+    };
+    let runners: Vec<&str> = [
+        (offense.first.is_some(), "1st"),
+        (offense.second.is_some(), "2nd"),
+        (offense.third.is_some(), "3rd"),
+    ]
+    .into_iter()
+    .filter_map(|(on_base, base)| on_base.then_some(base))
+    .collect();
+
+    match runners.len() {
+        0 => Some(count),
+        3 => Some(format!("{count}\nLoaded")),
+        _ => Some(format!("{count}\n{}", runners.join(", "))),
+    }
+}
+
+fn prepare_feeds(game: &GameData, display_mode: &DisplayMode, charset: Charset) -> String {
     let Some(feeds) = &game.broadcasts else {
         return String::new();
     };
@@ -324,6 +656,24 @@ fn prepare_feeds(game: &GameData, display_mode: &DisplayMode) -> String {
     radio_feeds.sort();
     let radio_feeds = radio_feeds.join(separator);
 
+    if charset == Charset::Ascii {
+        let (tv_label, radio_label, both_label) = match is_compact {
+            true => ("T:", "R:", "TR:"),
+            false => ("TV:   ", "Radio:", "TV/Radio:"),
+        };
+        return match (tv_feeds.is_empty(), radio_feeds.is_empty()) {
+            (true, true) => String::new(),
+            (false, true) => format!("{}{spacing}{tv_feeds}", tv_label.trim_end()),
+            (true, false) => format!("{radio_label}{spacing}{radio_feeds}"),
+            (false, false) if tv_feeds == radio_feeds => {
+                format!("{both_label}{spacing}{tv_feeds}")
+            }
+            (false, false) => {
+                format!("{tv_label}{spacing}{tv_feeds}\n{radio_label}{spacing}{radio_feeds}")
+            }
+        };
+    }
+
     match (tv_feeds.is_empty(), radio_feeds.is_empty()) {
         (true, true) => String::new(),
         (false, true) => format!("📺 {spacing}{tv_feeds}"),
@@ -338,7 +688,11 @@ fn prepare_feeds(game: &GameData, display_mode: &DisplayMode) -> String {
     }
 }
 
-fn prepare_highlights(game: &GameData, display_mode: &DisplayMode) -> String {
+fn prepare_highlights(game: &GameData, display_mode: &DisplayMode, reveal: bool) -> String {
+    // A recap or condensed game means the game is over.
+    if !reveal {
+        return String::new();
+    }
     if let Some(media) = &game.content.media
         && let Some(highlights) = &media.epg_alternate
     {
@@ -370,26 +724,202 @@ fn prepare_highlights(game: &GameData, display_mode: &DisplayMode) -> String {
     }
 }
 
-pub fn prepare_schedule_data(
-    schedule: DaySchedule,
-    display_mode: &DisplayMode,
-    scores: bool,
-) -> (Vec<GameRow>, String) {
+fn prepare_row(game: &GameData, options: &ScheduleOptions) -> GameRow {
+    let display_mode = &options.display_mode;
+    let pitchers = prepare_pitchers(game, display_mode);
+
+    // Without a pitchers column, starters go on a second line under the matchup.
+    let mut matchup = prepare_matchup(game, options);
+    if options.probable_pitchers
+        && !options.columns.contains(&Column::Pitchers)
+        && let Some((away, home)) = &pitchers
+    {
+        matchup = match display_mode {
+            DisplayMode::Compact => format!("{matchup}\n  {away}/{home}"),
+            _ => format!("{matchup}\n  {away} v {home}"),
+        };
+    }
+
+    let reveal = options.spoilers.reveals(game);
+    let mut state = prepare_state(game, display_mode, reveal);
+    if reveal
+        && options.live_situation
+        && let Some(situation) = prepare_situation(game, display_mode)
+    {
+        state = format!("{state}\n{situation}");
+    }
+
+    GameRow {
+        matchup,
+        series: prepare_series(game),
+        score: prepare_score(game, reveal),
+        state,
+        feeds: prepare_feeds(game, display_mode, options.charset),
+        highlights: prepare_highlights(game, display_mode, reveal),
+        pitchers: pitchers
+            .map(|(away, home)| format!("{away}\n{home}"))
+            .unwrap_or_default(),
+        venue: prepare_venue(game),
+        tv: prepare_tv(game, display_mode),
+        records: prepare_records(game, display_mode, reveal),
+        weather: prepare_weather(game, display_mode, options.charset),
+        game_pk: game.game_pk,
+        team_ids: [game.teams.away.team.id, game.teams.home.team.id],
+        // Coloring close games would give away the score when it's hidden.
+        critical: reveal && game.is_critical(options.critical_inning, options.critical_run_margin),
+    }
+}
+
+fn is_favorite(game: &GameData, favorites: &[TeamCode]) -> bool {
+    favorites.iter().any(|&code| {
+        let id = code.team().id;
+        game.teams.away.team.id == id || game.teams.home.team.id == id
+    })
+}
+
+/// Live games first, then upcoming, then finished; postponed and cancelled games last.
+fn status_rank(game: &GameData) -> u8 {
+    match game.status.abstract_game_state.as_str() {
+        "Live" => 0,
+        "Preview" => 1,
+        _ if matches!(
+            game.status.detailed_state.as_str(),
+            "Postponed" | "Cancelled" | "Suspended"
+        ) =>
+        {
+            3
+        }
+        _ => 2,
+    }
+}
+
+fn sort_games(games: &mut [&GameData], options: &ScheduleOptions) {
+    // Every sort is stable on top of first-pitch order, so ties stay chronological.
+    games.sort_by(|a, b| a.game_date.cmp(&b.game_date));
+    match options.sort {
+        SortOrder::Time => {}
+        SortOrder::Status => games.sort_by_key(|game| status_rank(game)),
+        SortOrder::Closeness => games.sort_by_key(|game| {
+            // Sorting by a hidden score would give it away, so those games go last.
+            let margin = game.run_margin().filter(|_| options.spoilers.reveals(game));
+            (margin.is_none(), margin)
+        }),
+    }
+}
+
+fn group_games<'a>(
+    games: Vec<&'a GameData>,
+    options: &ScheduleOptions,
+) -> Vec<(Option<String>, Vec<&'a GameData>)> {
+    match options.group_by {
+        GroupBy::None => vec![(None, games)],
+        GroupBy::Favorites => {
+            let (favorites, others): (Vec<_>, Vec<_>) = games
+                .into_iter()
+                .partition(|game| is_favorite(game, &options.favorites));
+            match (favorites.is_empty(), others.is_empty()) {
+                (true, _) => vec![(None, others)],
+                (false, true) => vec![(None, favorites)],
+                (false, false) => vec![
+                    (Some("Favorites".to_string()), favorites),
+                    (Some("Other Games".to_string()), others),
+                ],
+            }
+        }
+        GroupBy::Division => {
+            // Keyed by the home team's division; non-MLB clubs (e.g. spring training) go last.
+            type DivisionKey = (bool, Option<(League, DivisionRegion)>);
+            let mut groups: BTreeMap<DivisionKey, (String, Vec<_>)> = BTreeMap::new();
+            for game in games {
+                let division = Team::try_find_by_id(&game.teams.home.team.id).map(|t| t.division);
+                let key = division.map(|d| (d.league, d.name));
+                let title = division.map_or("Other Games".to_string(), |d| d.to_string());
+                groups
+                    .entry((key.is_none(), key))
+                    .or_insert((title, Vec::new()))
+                    .1
+                    .push(game);
+            }
+            groups
+                .into_values()
+                .map(|(title, games)| (Some(title), games))
+                .collect()
+        }
+        GroupBy::Time => {
+            let mut groups: Vec<(String, Vec<&GameData>)> = Vec::new();
+            for game in games {
+                let time = prepare_game_time(game, options);
+                match groups.iter_mut().find(|(t, _)| *t == time) {
+                    Some((_, group)) => group.push(game),
+                    None => groups.push((time, vec![game])),
+                }
+            }
+            groups.sort_by_key(|(_, group)| group.iter().map(|g| &g.game_date).min().cloned());
+            groups
+                .into_iter()
+                .map(|(time, games)| (Some(time), games))
+                .collect()
+        }
+    }
+}
+
+/// Sort and group a day's games into sections according to `options`.
+pub fn prepare_schedule_sections(
+    schedule: &DaySchedule,
+    options: &ScheduleOptions,
+) -> (Vec<ScheduleSection>, String) {
     let weekday = schedule.date.format("%A");
     let header_date = format!("{} {}", schedule.date, weekday);
 
-    let rows: Vec<GameRow> = schedule
-        .games
-        .iter()
-        .map(|game| GameRow {
-            matchup: prepare_matchup(game, display_mode),
-            series: prepare_series(game),
-            score: prepare_score(game, scores),
-            state: prepare_state(game, display_mode),
-            feeds: prepare_feeds(game, display_mode),
-            highlights: prepare_highlights(game, display_mode),
+    let mut games: Vec<&GameData> = schedule.games.iter().collect();
+    sort_games(&mut games, options);
+
+    let sections = group_games(games, options)
+        .into_iter()
+        .map(|(title, mut games)| {
+            if options.favorites_first {
+                games.sort_by_key(|game| !is_favorite(game, &options.favorites));
+            }
+            ScheduleSection {
+                title,
+                rows: games
+                    .iter()
+                    .map(|game| prepare_row(game, options))
+                    .collect(),
+            }
         })
         .collect();
 
+    (sections, header_date)
+}
+
+/// All of a day's rows in section order, for views that show a single table.
+pub fn prepare_schedule_data(
+    schedule: &DaySchedule,
+    options: &ScheduleOptions,
+) -> (Vec<GameRow>, String) {
+    let (sections, header_date) = prepare_schedule_sections(schedule, options);
+    let rows = sections.into_iter().flat_map(|s| s.rows).collect();
     (rows, header_date)
 }
+
+/// Render a day's schedule as one colored table per section.
+pub fn format_schedule(
+    schedule: &DaySchedule,
+    config: &AppConfig,
+    options: &ScheduleOptions,
+) -> String {
+    let (sections, header_date) = prepare_schedule_sections(schedule, options);
+    let tables: Vec<String> = sections
+        .into_iter()
+        .map(|section| {
+            let table = create_schedule_table(section.rows, &header_date, options);
+            let table = color_schedule_table(table, config, options);
+            match section.title {
+                Some(title) => format!("{title}\n{table}"),
+                None => table.to_string(),
+            }
+        })
+        .collect();
+    tables.join("\n\n")
+}
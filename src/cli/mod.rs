@@ -1,4 +1,15 @@
 pub mod args;
+pub mod article_display;
+pub mod calendar;
+pub mod completions;
+pub mod dates;
 pub mod display;
+pub mod output;
+pub mod player_display;
+pub mod plays_display;
+pub mod roster_display;
+pub mod spoilers;
+pub mod standings_display;
+pub mod watch;
 
 pub use args::Cli;
@@ -0,0 +1,237 @@
+use crate::{
+    api::stats::schedule::{DaySchedule, GameData},
+    cli::spoilers::SpoilerPolicy,
+    data::teamdata::Team,
+};
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Ndjson,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "ndjson" | "jsonl" => Ok(Self::Ndjson),
+            _ => anyhow::bail!("Invalid output format: {s}; expected table, json, csv or ndjson"),
+        }
+    }
+}
+
+/// One game per record. Fields are flat so the same struct works for CSV.
+#[derive(Debug, Serialize)]
+pub struct GameRecord {
+    pub game_pk: u64,
+    pub date: NaiveDate,
+    pub start_time: String,
+    pub game_number: u8,
+    pub away: String,
+    pub away_code: String,
+    pub home: String,
+    pub home_code: String,
+    pub series_game_number: u8,
+    pub games_in_series: u8,
+    pub state: String,
+    pub away_score: Option<u8>,
+    pub home_score: Option<u8>,
+    pub inning: Option<u8>,
+    pub tv_feeds: String,
+    pub radio_feeds: String,
+    pub highlights: String,
+}
+
+/// A playback URL with the game and feed it plays, e.g. `home`, `away-audio` or `recap`.
+#[derive(Debug, Serialize)]
+pub struct PlaybackRecord {
+    pub game_pk: u64,
+    pub feed: String,
+    pub url: String,
+}
+
+impl GameRecord {
+    /// Results are left out for games the spoiler policy hides. Clubs outside MLB, like
+    /// the All-Star teams, have an empty code.
+    pub fn new(date: NaiveDate, game: &GameData, spoilers: &SpoilerPolicy) -> Self {
+        let code = |id| Team::try_find_by_id(id).map_or_else(String::new, |t| t.code.to_string());
+        let reveal = spoilers.reveals(game);
+        let linescore = game.linescore.as_ref().filter(|_| reveal);
+
+        let state = match reveal || game.status.abstract_game_state == "Preview" {
+            true => game.status.detailed_state.clone(),
+            false => game.status.abstract_game_state.clone(),
+        };
+
+        // A recap or condensed game means the game is over.
+        let mut highlights: Vec<String> = game
+            .content
+            .media
+            .as_ref()
+            .filter(|_| reveal)
+            .and_then(|m| m.epg_alternate.as_ref())
+            .map(|h| h.iter().map(|h| h.title.to_string()).collect())
+            .unwrap_or_default();
+        highlights.sort();
+
+        Self {
+            game_pk: game.game_pk,
+            date,
+            start_time: game.game_date.clone(),
+            game_number: game.game_number,
+            away: game.teams.away.team.name.clone(),
+            away_code: code(&game.teams.away.team.id),
+            home: game.teams.home.team.name.clone(),
+            home_code: code(&game.teams.home.team.id),
+            series_game_number: game.series_game_number,
+            games_in_series: game.games_in_series,
+            state,
+            away_score: linescore.and_then(|l| l.teams.away.runs),
+            home_score: linescore.and_then(|l| l.teams.home.runs),
+            inning: linescore.and_then(|l| l.current_inning),
+            tv_feeds: game.streaming_feeds(true).join(","),
+            radio_feeds: game.streaming_feeds(false).join(","),
+            highlights: highlights.join(","),
+        }
+    }
+
+    pub fn from_schedules(schedules: &[DaySchedule], spoilers: &SpoilerPolicy) -> Vec<Self> {
+        schedules
+            .iter()
+            .flat_map(|day| {
+                day.games
+                    .iter()
+                    .map(|game| Self::new(day.date, game, spoilers))
+            })
+            .collect()
+    }
+}
+
+/// Write records to stdout in a machine-readable format.
+pub fn write_records<T: Serialize>(records: &[T], format: OutputFormat) -> Result<()> {
+    let mut out = io::stdout().lock();
+
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut out, records)?;
+            writeln!(out)?;
+        }
+        OutputFormat::Ndjson => {
+            for record in records {
+                serde_json::to_writer(&mut out, record)?;
+                writeln!(out)?;
+            }
+        }
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+        OutputFormat::Table => anyhow::bail!("Table output is rendered by the display module"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_format_from_str_accepts_aliases() {
+        assert_eq!(OutputFormat::from_str("JSON").unwrap(), OutputFormat::Json);
+        assert_eq!(
+            OutputFormat::from_str("jsonl").unwrap(),
+            OutputFormat::Ndjson
+        );
+        assert!(OutputFormat::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn hidden_games_leave_out_results_and_highlights() {
+        // HighlightType borrows its string, so this goes through from_str.
+        let json = serde_json::json!({
+            "gamePk": 776543,
+            "gameDate": "2025-07-16T23:05:00Z",
+            "status": {
+                "abstractGameState": "Final",
+                "detailedState": "Final",
+                "statusCode": "F",
+                "codedGameState": "F",
+            },
+            "teams": {
+                "home": { "team": { "id": 120, "name": "Washington Nationals" } },
+                "away": { "team": { "id": 141, "name": "Toronto Blue Jays" } },
+            },
+            "linescore": {
+                "currentInning": 9,
+                "teams": { "home": { "runs": 3 }, "away": { "runs": 2 } },
+            },
+            "content": {
+                "media": {
+                    "epgAlternate": [
+                        { "title": "Daily Recap", "items": [] },
+                        { "title": "Extended Highlights", "items": [] },
+                    ],
+                },
+            },
+            "gameNumber": 1,
+            "gamesInSeries": 3,
+            "seriesGameNumber": 1,
+        });
+        let game: GameData = serde_json::from_str(&json.to_string()).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 7, 16).unwrap();
+
+        let shown = GameRecord::new(date, &game, &SpoilerPolicy::show_all());
+        assert_eq!(shown.highlights, "CG,Recap");
+        assert_eq!(shown.home_score, Some(3));
+
+        let hidden = GameRecord::new(date, &game, &SpoilerPolicy::hide_all());
+        assert_eq!(hidden.highlights, "");
+        assert_eq!(hidden.home_score, None);
+        assert_eq!(hidden.inning, None);
+    }
+
+    #[test]
+    fn clubs_outside_mlb_have_an_empty_code() {
+        let json = serde_json::json!({
+            "gamePk": 778777,
+            "gameDate": "2025-07-16T00:00:00Z",
+            "status": {
+                "abstractGameState": "Final",
+                "detailedState": "Final",
+                "statusCode": "F",
+                "codedGameState": "F",
+            },
+            "teams": {
+                "home": { "team": { "id": 160, "name": "National League All-Stars" } },
+                "away": { "team": { "id": 159, "name": "American League All-Stars" } },
+            },
+            "content": {},
+            "gameNumber": 1,
+            "gamesInSeries": 1,
+            "seriesGameNumber": 1,
+        });
+        let game: GameData = serde_json::from_str(&json.to_string()).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 7, 15).unwrap();
+
+        let record = GameRecord::new(date, &game, &SpoilerPolicy::show_all());
+        assert_eq!(record.away, "American League All-Stars");
+        assert_eq!(record.away_code, "");
+        assert_eq!(record.home_code, "");
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    api::stats::people::{Handedness, Person, PlayerStats, StatGroup, StatLine},
+    cli::display::{Charset, schedule_table_theme},
+    data::teamdata::Team,
+};
+use tabled::{
+    Table,
+    builder::Builder,
+    settings::{Alignment, object::Columns},
+};
+
+const HITTING_HEADERS: [&str; 13] = [
+    "G", "AB", "R", "H", "HR", "RBI", "SB", "BB", "SO", "AVG", "OBP", "SLG", "OPS",
+];
+const PITCHING_HEADERS: [&str; 12] = [
+    "G", "GS", "W", "L", "SV", "IP", "H", "ER", "BB", "SO", "ERA", "WHIP",
+];
+
+fn opt_num(value: Option<u32>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+fn opt_str(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+fn stat_cells(stat: &StatLine, group: StatGroup) -> Vec<String> {
+    match group {
+        StatGroup::Hitting => vec![
+            opt_num(stat.games_played),
+            opt_num(stat.at_bats),
+            opt_num(stat.runs),
+            opt_num(stat.hits),
+            opt_num(stat.home_runs),
+            opt_num(stat.rbi),
+            opt_num(stat.stolen_bases),
+            opt_num(stat.base_on_balls),
+            opt_num(stat.strike_outs),
+            opt_str(&stat.avg),
+            opt_str(&stat.obp),
+            opt_str(&stat.slg),
+            opt_str(&stat.ops),
+        ],
+        StatGroup::Pitching => vec![
+            opt_num(stat.games_played),
+            opt_num(stat.games_started),
+            opt_num(stat.wins),
+            opt_num(stat.losses),
+            opt_num(stat.saves),
+            opt_str(&stat.innings_pitched),
+            opt_num(stat.hits),
+            opt_num(stat.earned_runs),
+            opt_num(stat.base_on_balls),
+            opt_num(stat.strike_outs),
+            opt_str(&stat.era),
+            opt_str(&stat.whip),
+        ],
+    }
+}
+
+fn stat_table(
+    label: &str,
+    group: StatGroup,
+    rows: Vec<(String, &StatLine)>,
+    charset: Charset,
+) -> Table {
+    let headers: &[&str] = match group {
+        StatGroup::Hitting => &HITTING_HEADERS,
+        StatGroup::Pitching => &PITCHING_HEADERS,
+    };
+
+    let mut builder = Builder::new();
+    builder.push_record(std::iter::once(label).chain(headers.iter().copied()));
+    for (row_label, stat) in rows {
+        builder.push_record(std::iter::once(row_label).chain(stat_cells(stat, group)));
+    }
+
+    let mut table = builder.build();
+    table
+        .with(schedule_table_theme(charset))
+        .modify(Columns::new(1..), Alignment::right());
+    table
+}
+
+fn team_code_or_unknown(team_id: Option<u32>) -> String {
+    team_id
+        .and_then(|id| Team::try_find_by_id(&id))
+        .map_or_else(|| "---".to_string(), |t| t.code.to_string())
+}
+
+pub fn format_bio(person: &Person) -> String {
+    let number = person
+        .primary_number
+        .as_ref()
+        .map(|n| format!(" #{n}"))
+        .unwrap_or_default();
+    let position = person
+        .primary_position
+        .as_ref()
+        .map(|p| format!(" {}", p.abbreviation))
+        .unwrap_or_default();
+
+    // Current team may be a minor league affiliate that isn't in TEAMS.
+    let team = match &person.current_team {
+        Some(team) => Team::try_find_by_id(&team.id)
+            .map_or_else(|| "Minor leagues".to_string(), |t| t.name.to_string()),
+        None if person.active => "Free agent".to_string(),
+        None => "Inactive".to_string(),
+    };
+
+    let hand = |h: &Option<Handedness>| {
+        h.as_ref()
+            .map_or_else(|| "?".to_string(), |h| h.code.clone())
+    };
+
+    let mut lines = vec![
+        format!("{}{}{}  |  {}", person.full_name, number, position, team),
+        format!(
+            "B/T: {}/{}   Age: {}   Ht/Wt: {} / {} lb",
+            hand(&person.bat_side),
+            hand(&person.pitch_hand),
+            person
+                .current_age
+                .map_or_else(|| "-".to_string(), |a| a.to_string()),
+            opt_str(&person.height),
+            person
+                .weight
+                .map_or_else(|| "-".to_string(), |w| w.to_string()),
+        ),
+    ];
+
+    if let Some(birth_date) = &person.birth_date {
+        let birthplace = [&person.birth_city, &person.birth_country]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("Born: {birth_date} in {birthplace}"));
+    }
+    if let Some(debut) = &person.mlb_debut_date {
+        lines.push(format!("MLB debut: {debut}"));
+    }
+
+    lines.join("\n")
+}
+
+pub fn create_summary_table(stats: &PlayerStats, season: i32, charset: Charset) -> Option<Table> {
+    let mut rows = Vec::new();
+    if let Some(split) = &stats.season {
+        let label = split.season.clone().unwrap_or_else(|| season.to_string());
+        rows.push((label, &split.stat));
+    }
+    if let Some(split) = &stats.career {
+        rows.push(("Career".to_string(), &split.stat));
+    }
+
+    match rows.is_empty() {
+        true => None,
+        false => Some(stat_table(
+            group_label(stats.group),
+            stats.group,
+            rows,
+            charset,
+        )),
+    }
+}
+
+pub fn create_game_log_table(stats: &PlayerStats, charset: Charset) -> Option<Table> {
+    if stats.game_log.is_empty() {
+        return None;
+    }
+
+    let rows = stats
+        .game_log
+        .iter()
+        .map(|split| {
+            let date = split.date.clone().unwrap_or_default();
+            let opponent = team_code_or_unknown(split.opponent.as_ref().map(|t| t.id));
+            let prefix = if split.is_home.unwrap_or(true) {
+                "vs"
+            } else {
+                "@"
+            };
+            (format!("{date} {prefix} {opponent}"), &split.stat)
+        })
+        .collect();
+
+    Some(stat_table("Game Log", stats.group, rows, charset))
+}
+
+pub fn create_splits_table(stats: &PlayerStats, charset: Charset) -> Option<Table> {
+    if stats.splits.is_empty() {
+        return None;
+    }
+
+    let rows = stats
+        .splits
+        .iter()
+        .map(|split| {
+            let label = split
+                .split
+                .as_ref()
+                .map_or_else(String::new, |s| s.description.clone());
+            (label, &split.stat)
+        })
+        .collect();
+
+    Some(stat_table("Splits", stats.group, rows, charset))
+}
+
+fn group_label(group: StatGroup) -> &'static str {
+    match group {
+        StatGroup::Hitting => "Hitting",
+        StatGroup::Pitching => "Pitching",
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    api::stats::feed::{Play, PlayEvent},
+    data::teamdata::Team,
+};
+use textwrap::Options;
+
+fn ordinal(n: u8) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+fn format_pitch(number: usize, event: &PlayEvent) -> String {
+    let call = event.details.description.as_deref().unwrap_or("Pitch");
+    let mut line = format!("{number}. {call}");
+
+    if let Some(pitch_type) = &event.details.kind {
+        line.push_str(&format!(" - {}", pitch_type.description));
+    }
+    if let Some(speed) = event.pitch_data.as_ref().and_then(|p| p.start_speed) {
+        line.push_str(&format!(", {speed:.1} mph"));
+    }
+    if let Some(count) = &event.count {
+        line.push_str(&format!(" ({}-{})", count.balls, count.strikes));
+    }
+    line
+}
+
+/// Render plays grouped under inning headers, optionally with each pitch of the at-bat.
+pub fn format_plays(
+    plays: &[&Play],
+    away: &Team,
+    home: &Team,
+    show_pitches: bool,
+    width: usize,
+) -> String {
+    let play_opts = Options::new(width)
+        .initial_indent("  ")
+        .subsequent_indent("  ");
+    let mut lines = Vec::new();
+    let mut current_half = None;
+
+    for play in plays {
+        let half = (play.about.inning, play.about.is_top_inning);
+        if current_half != Some(half) {
+            if current_half.is_some() {
+                lines.push(String::new());
+            }
+            let half_str = if play.about.is_top_inning {
+                "Top"
+            } else {
+                "Bottom"
+            };
+            lines.push(format!("{half_str} {}", ordinal(play.about.inning)));
+            current_half = Some(half);
+        }
+
+        let mut description = play.result.description.clone().unwrap_or_default();
+        if play.about.is_scoring_play {
+            description.push_str(&format!(
+                " [{} {}, {} {}]",
+                away.code,
+                play.result.away_score.unwrap_or(0),
+                home.code,
+                play.result.home_score.unwrap_or(0)
+            ));
+        }
+        lines.push(textwrap::fill(&description, &play_opts));
+
+        if show_pitches {
+            let pitches = play.play_events.iter().filter(|e| e.is_pitch);
+            for (idx, event) in pitches.enumerate() {
+                lines.push(format!("      {}", format_pitch(idx + 1, event)));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordinal_handles_teens_and_suffixes() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(4), "4th");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(13), "13th");
+        assert_eq!(ordinal(21), "21st");
+    }
+}
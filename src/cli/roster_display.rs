@@ -0,0 +1,135 @@
+use crate::{
+    api::stats::roster::{PositionGroup, RosterEntry, RosterType},
+    cli::display::{Charset, schedule_table_theme},
+    data::teamdata::Team,
+};
+use tabled::{
+    Table,
+    builder::Builder,
+    settings::{Alignment, object::Columns},
+};
+
+fn handedness(entry: &RosterEntry) -> String {
+    let bats = entry
+        .person
+        .bat_side
+        .as_ref()
+        .map_or("?", |h| h.code.as_str());
+    let throws = entry
+        .person
+        .pitch_hand
+        .as_ref()
+        .map_or("?", |h| h.code.as_str());
+    format!("{bats}/{throws}")
+}
+
+fn jersey_sort_key(entry: &RosterEntry) -> u32 {
+    entry
+        .jersey_number
+        .as_deref()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(u32::MAX)
+}
+
+fn roster_table(header: &str, entries: &[&RosterEntry], charset: Charset) -> Table {
+    let mut builder = Builder::new();
+    builder.push_record(["#", header, "Pos", "B/T", "Status"]);
+
+    for entry in entries {
+        let status = match entry.is_injured() {
+            true => entry.status.description.clone(),
+            false => String::new(),
+        };
+        builder.push_record([
+            entry.jersey_number.clone().unwrap_or_default(),
+            entry.person.full_name.clone(),
+            entry.position.abbreviation.clone(),
+            handedness(entry),
+            status,
+        ]);
+    }
+
+    let mut table = builder.build();
+    table
+        .with(schedule_table_theme(charset))
+        .modify(Columns::one(0), Alignment::right());
+    table
+}
+
+/// Depth charts list every player at each position in order, so render one row per position.
+fn depth_chart_table(header: &str, entries: &[&RosterEntry], charset: Charset) -> Table {
+    let mut positions: Vec<(&str, Vec<String>)> = Vec::new();
+    for entry in entries {
+        let abbrev = entry.position.abbreviation.as_str();
+        let name = match entry.is_injured() {
+            true => format!("{} (IL)", entry.person.full_name),
+            false => entry.person.full_name.clone(),
+        };
+        match positions.iter_mut().find(|(pos, _)| *pos == abbrev) {
+            Some((_, names)) => names.push(name),
+            None => positions.push((abbrev, vec![name])),
+        }
+    }
+
+    let mut builder = Builder::new();
+    builder.push_record(["Pos", header]);
+    for (pos, names) in positions {
+        builder.push_record([pos.to_string(), names.join(", ")]);
+    }
+
+    let mut table = builder.build();
+    table.with(schedule_table_theme(charset));
+    table
+}
+
+pub fn create_roster_tables(
+    team: &Team,
+    roster_type: RosterType,
+    entries: &[RosterEntry],
+    position_group: Option<PositionGroup>,
+    charset: Charset,
+) -> Vec<Table> {
+    let header = format!("{} {}", team.name, roster_type);
+    let filtered: Vec<&RosterEntry> = entries
+        .iter()
+        .filter(|e| position_group.is_none_or(|group| e.in_group(group)))
+        .collect();
+
+    if roster_type == RosterType::DepthChart {
+        return vec![depth_chart_table(&header, &filtered, charset)];
+    }
+
+    let groups = match position_group {
+        Some(group) => vec![group],
+        None => vec![PositionGroup::Pitchers, PositionGroup::Hitters],
+    };
+
+    groups
+        .into_iter()
+        .filter_map(|group| {
+            let mut players: Vec<&RosterEntry> = filtered
+                .iter()
+                .copied()
+                .filter(|e| match group {
+                    // Two-way players are listed once, with the pitchers, in the unfiltered view.
+                    PositionGroup::Hitters if position_group.is_none() => !e.is_pitcher(),
+                    _ => e.in_group(group),
+                })
+                .collect();
+            if players.is_empty() {
+                return None;
+            }
+            players.sort_by_key(|e| jersey_sort_key(e));
+
+            let label = match group {
+                PositionGroup::Pitchers => "Pitchers",
+                PositionGroup::Hitters => "Position Players",
+            };
+            Some(roster_table(
+                &format!("{header}: {label}"),
+                &players,
+                charset,
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,215 @@
+use crate::{
+    api::stats::schedule::GameData,
+    config::{AppConfig, SpoilerTeam, config::project_dirs},
+};
+use chrono::NaiveDate;
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// Decides which games may show results: scores, innings, final states, recaps and plays.
+#[derive(Debug, Clone, Default)]
+pub struct SpoilerPolicy {
+    hide_all: bool,
+    hidden_team_ids: Vec<u32>,
+    watched_game_pks: Vec<u64>,
+}
+
+impl SpoilerPolicy {
+    pub fn show_all() -> Self {
+        Self::default()
+    }
+
+    pub fn hide_all() -> Self {
+        Self {
+            hide_all: true,
+            ..Self::default()
+        }
+    }
+
+    /// `scores = false` hides every result; otherwise only `spoiler_teams` games are
+    /// hidden, until they've been watched.
+    pub fn from_config(config: &AppConfig) -> Self {
+        if !config.display.scores {
+            return Self::hide_all();
+        }
+
+        let mut hidden_team_ids: Vec<u32> = config
+            .display
+            .spoiler_teams
+            .iter()
+            .flat_map(|entry| match entry {
+                SpoilerTeam::Favorites => config.favorites.teams.clone(),
+                SpoilerTeam::Team(code) => vec![*code],
+            })
+            .map(|code| code.team().id)
+            .collect();
+        hidden_team_ids.sort_unstable();
+        hidden_team_ids.dedup();
+
+        let watched_game_pks = match hidden_team_ids.is_empty() {
+            true => Vec::new(),
+            false => load_watched(),
+        };
+
+        Self {
+            hide_all: false,
+            hidden_team_ids,
+            watched_game_pks,
+        }
+    }
+
+    /// Stop hiding a `spoiler_teams` game once it has been played back.
+    pub fn mark_watched(&mut self, game_pk: u64) {
+        self.watched_game_pks.push(game_pk);
+    }
+
+    fn reveals_matchup(&self, away_id: u32, home_id: u32) -> bool {
+        !self.hide_all
+            && !self.hidden_team_ids.contains(&away_id)
+            && !self.hidden_team_ids.contains(&home_id)
+    }
+
+    pub fn reveals(&self, game: &GameData) -> bool {
+        self.reveals_matchup(game.teams.away.team.id, game.teams.home.team.id)
+            || (!self.hide_all && self.watched_game_pks.contains(&game.game_pk))
+    }
+
+    pub fn hides_any(&self) -> bool {
+        self.hide_all || !self.hidden_team_ids.is_empty()
+    }
+
+    /// Standings as of `date` would give away the result of a hidden game that's
+    /// under way or over, so they're shown as of the day before instead.
+    pub fn standings_date(&self, date: NaiveDate, games: &[GameData]) -> NaiveDate {
+        let spoiled = games
+            .iter()
+            .any(|game| game.status.abstract_game_state != "Preview" && !self.reveals(game));
+        match spoiled {
+            true => date.pred_opt().unwrap_or(date),
+            false => date,
+        }
+    }
+}
+
+/// One `game_pk` per line, for every game played back.
+fn watched_file() -> PathBuf {
+    project_dirs().data_dir().join("watched")
+}
+
+fn load_watched() -> Vec<u64> {
+    let contents = fs::read_to_string(watched_file()).unwrap_or_default();
+    contents
+        .lines()
+        .filter_map(|line| line.trim().parse().ok())
+        .collect()
+}
+
+/// Remember that a game was played back, so `spoiler_teams` stops hiding its result.
+/// Failing to save this shouldn't stop playback, so errors are only logged.
+pub fn record_watched(game_pk: u64) {
+    let append = || -> io::Result<()> {
+        let path = watched_file();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{game_pk}")
+    };
+    if let Err(e) = append() {
+        tracing::warn!("Failed to record game {game_pk} as watched: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hide_all_masks_every_game() {
+        assert!(!SpoilerPolicy::hide_all().reveals_matchup(120, 141));
+        assert!(SpoilerPolicy::show_all().reveals_matchup(120, 141));
+    }
+
+    #[test]
+    fn hidden_teams_mask_either_side_of_the_matchup() {
+        let policy = SpoilerPolicy {
+            hidden_team_ids: vec![120],
+            ..SpoilerPolicy::default()
+        };
+        assert!(!policy.reveals_matchup(120, 141));
+        assert!(!policy.reveals_matchup(141, 120));
+        assert!(policy.reveals_matchup(141, 147));
+    }
+
+    fn game(game_pk: u64, state: &str, home_id: u32) -> GameData {
+        serde_json::from_value(serde_json::json!({
+            "gamePk": game_pk,
+            "gameDate": "2025-07-16T23:05:00Z",
+            "status": {
+                "abstractGameState": state,
+                "detailedState": state,
+                "statusCode": "F",
+                "codedGameState": "F",
+            },
+            "teams": {
+                "home": { "team": { "id": home_id, "name": "Home" } },
+                "away": { "team": { "id": 141, "name": "Toronto Blue Jays" } },
+            },
+            "content": {},
+            "gameNumber": 1,
+            "gamesInSeries": 3,
+            "seriesGameNumber": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn watched_games_are_revealed_unless_everything_is_hidden() {
+        let game = game(776543, "Final", 120);
+
+        let mut policy = SpoilerPolicy {
+            hidden_team_ids: vec![120],
+            ..SpoilerPolicy::default()
+        };
+        assert!(!policy.reveals(&game));
+        policy.mark_watched(776543);
+        assert!(policy.reveals(&game));
+
+        let mut policy = SpoilerPolicy::hide_all();
+        policy.mark_watched(776543);
+        assert!(!policy.reveals(&game));
+    }
+
+    #[test]
+    fn standings_skip_today_when_a_hidden_game_has_started() {
+        let today = NaiveDate::from_ymd_opt(2025, 7, 16).unwrap();
+        let yesterday = NaiveDate::from_ymd_opt(2025, 7, 15).unwrap();
+        let policy = SpoilerPolicy {
+            hidden_team_ids: vec![120],
+            ..SpoilerPolicy::default()
+        };
+
+        let upcoming = [game(1, "Preview", 120), game(2, "Final", 147)];
+        assert_eq!(policy.standings_date(today, &upcoming), today);
+
+        let mut played = [game(1, "Live", 120), game(2, "Final", 147)];
+        assert_eq!(policy.standings_date(today, &played), yesterday);
+        assert_eq!(
+            SpoilerPolicy::show_all().standings_date(today, &played),
+            today
+        );
+
+        let mut watched = policy.clone();
+        watched.mark_watched(1);
+        assert_eq!(watched.standings_date(today, &played), today);
+
+        played[0] = game(1, "Preview", 147);
+        assert_eq!(
+            SpoilerPolicy::hide_all().standings_date(today, &played),
+            yesterday
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    api::stats::standings::DivisionStandings,
+    cli::display::{Charset, schedule_table_theme},
+    config::Favorites,
+};
+use tabled::{
+    Table,
+    builder::Builder,
+    settings::{
+        Alignment,
+        object::{Columns, Rows},
+    },
+};
+
+fn division_table(
+    standings: &DivisionStandings,
+    charset: Charset,
+    favorites: Option<&Favorites>,
+) -> Table {
+    let title = standings
+        .division()
+        .map_or_else(|| "Division".to_string(), ToString::to_string);
+
+    let mut builder = Builder::new();
+    builder.push_record([title.as_str(), "W", "L", "PCT", "GB", "L10", "STRK", "DIFF"]);
+    for record in &standings.team_records {
+        let diff = record.run_differential.map_or_else(String::new, |diff| {
+            if diff > 0 {
+                format!("+{diff}")
+            } else {
+                diff.to_string()
+            }
+        });
+        builder.push_record([
+            record.team.name.clone(),
+            record.wins.to_string(),
+            record.losses.to_string(),
+            record.winning_percentage.clone(),
+            record.games_back.clone(),
+            record.last_ten().unwrap_or_default(),
+            record
+                .streak
+                .as_ref()
+                .map(|streak| streak.streak_code.clone())
+                .unwrap_or_default(),
+            diff,
+        ]);
+    }
+
+    let mut table = builder.build();
+    table
+        .with(schedule_table_theme(charset))
+        .modify(Columns::new(1..), Alignment::right());
+
+    if let Some(favorites) = favorites {
+        for (idx, record) in standings.team_records.iter().enumerate() {
+            if let Some(color) = favorites.color_for([record.team.id; 2]) {
+                table.modify(Rows::one(idx + 1), color);
+            }
+        }
+    }
+    table
+}
+
+/// One table per division, AL before NL and East to West. Favorite teams are
+/// highlighted when `favorites` is given.
+pub fn create_standings_tables(
+    mut standings: Vec<DivisionStandings>,
+    charset: Charset,
+    favorites: Option<&Favorites>,
+) -> Vec<Table> {
+    standings.sort_by_key(|division| {
+        division
+            .division()
+            .map(|division| (division.league, division.name))
+    });
+    standings
+        .iter()
+        .map(|division| division_table(division, charset, favorites))
+        .collect()
+}
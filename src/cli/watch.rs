@@ -0,0 +1,82 @@
+use crate::{
+    api::{session::MlbSession, stats::schedule::ScheduleFilter},
+    cli::display::{self, Charset, ScheduleOptions},
+    config::AppConfig,
+};
+use anyhow::Result;
+use chrono::{Local, NaiveDate};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::Duration;
+use tabled::settings::{Color, object::Rows};
+
+const CLEAR_SCREEN: &str = "\u{1b}[2J\u{1b}[H";
+
+/// Clear the screen before each redraw, or under plain output (NO_COLOR, a pipe or
+/// the ASCII theme) separate the redraws with a line so no escape codes are written.
+fn redraw_start(options: &ScheduleOptions) -> String {
+    match options.color && options.charset == Charset::Unicode {
+        true => CLEAR_SCREEN.to_string(),
+        false => format!("{}\n", "-".repeat(display::terminal_width())),
+    }
+}
+
+// Blink + reverse video; terminals without blink support still show the reversed row.
+fn flash_color() -> Color {
+    Color::new("\u{1b}[5;7m", "\u{1b}[25;27m")
+}
+
+/// Re-fetch and redraw the day's schedule every `interval` until interrupted.
+pub async fn watch_schedule<State>(
+    session: &MlbSession<State>,
+    date: NaiveDate,
+    filter: Option<&ScheduleFilter>,
+    options: &ScheduleOptions,
+    config: &AppConfig,
+    interval: Duration,
+) -> Result<()> {
+    let mut last_scores: HashMap<u64, String> = HashMap::new();
+
+    loop {
+        match session.fetch_schedule_by_date(&date, filter).await {
+            Ok(Some(schedule)) => {
+                let (rows, header_date) = display::prepare_schedule_data(&schedule, options);
+
+                // Flash rows whose score changed since the previous refresh.
+                let changed: Vec<usize> = rows
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, row)| {
+                        last_scores
+                            .get(&row.game_pk)
+                            .is_some_and(|prev| *prev != row.score)
+                    })
+                    .map(|(idx, _)| idx + 1) // Skip header row
+                    .collect();
+                last_scores = rows.iter().map(|r| (r.game_pk, r.score.clone())).collect();
+
+                let table = display::create_schedule_table(rows, &header_date, options);
+                let mut table = display::color_schedule_table(table, config, options);
+                for row_num in changed.into_iter().filter(|_| options.color) {
+                    table.modify(Rows::one(row_num), flash_color());
+                }
+
+                print!("{}{table}\n\n", redraw_start(options));
+            }
+            Ok(None) => print!("{}No games scheduled for {date}\n\n", redraw_start(options)),
+            Err(e) => {
+                // Keep the last good table on screen through transient network errors.
+                tracing::warn!("Failed to refresh schedule: {e:#}");
+            }
+        }
+
+        println!(
+            "Updated {} - refreshing every {}s (Ctrl-C to quit)",
+            Local::now().format("%H:%M:%S"),
+            interval.as_secs()
+        );
+        io::stdout().flush()?;
+
+        tokio::time::sleep(interval).await;
+    }
+}
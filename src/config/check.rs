@@ -0,0 +1,497 @@
+use super::color::ConfigColor;
+use super::config::{
+    Column, GroupBy, SortOrder, SpoilerTeam, ThemeSetting, TimeFormat, TimeZoneSetting,
+};
+use crate::data::teamdata::TeamCode;
+use anyhow::Result;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+use toml_edit::{ImDocument, Item, TableLike};
+
+/// A problem found in a config file, positioned at a 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn at(contents: &str, span: Option<Range<usize>>, message: impl Into<String>) -> Self {
+        let offset = span.map_or(0, |s| s.start).min(contents.len());
+        let before = &contents[..offset];
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .count()
+                + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Position a `toml` deserialization error, e.g. from `AppConfig::load`.
+    pub fn from_toml_error(contents: &str, err: &toml::de::Error) -> Self {
+        Self::at(contents, err.span(), err.message())
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// What a config key may hold.
+#[derive(Clone, Copy)]
+pub(super) enum Expect {
+    Bool,
+    Integer,
+    Text(fn(&str) -> Result<()>),
+    TextList(fn(&str) -> Result<()>),
+    /// A non-empty list without repeats, compared ignoring case, e.g. `columns`.
+    TextSet(fn(&str) -> Result<()>),
+    /// A media player command or path that must resolve to an executable.
+    Player,
+    Table(&'static [(&'static str, Expect)]),
+    /// A table of strings under validated keys, e.g. `[favorites.colors]`.
+    TextMap {
+        key: fn(&str) -> Result<()>,
+        value: fn(&str) -> Result<()>,
+    },
+    /// A table of user-named tables that all share one schema, e.g. `[profile.<name>]`.
+    Named(&'static [(&'static str, Expect)]),
+    /// No longer used, with what to do instead.
+    Deprecated(&'static str),
+}
+
+fn any_text(_: &str) -> Result<()> {
+    Ok(())
+}
+
+fn team_code(s: &str) -> Result<()> {
+    TeamCode::from_str(s).map(drop)
+}
+
+fn spoiler_team(s: &str) -> Result<()> {
+    SpoilerTeam::try_from(s.to_string()).map(drop)
+}
+
+fn parses<T: FromStr<Err = anyhow::Error>>(s: &str) -> Result<()> {
+    T::from_str(s).map(drop)
+}
+
+/// The problem with a `TextSet` list: no entries, or one given twice.
+pub(super) fn set_problem(name: &str, items: &[&str]) -> Option<String> {
+    if items.is_empty() {
+        return Some(format!("'{name}' needs at least one entry"));
+    }
+    items.iter().enumerate().find_map(|(idx, item)| {
+        items[..idx]
+            .iter()
+            .any(|earlier| earlier.eq_ignore_ascii_case(item))
+            .then(|| format!("'{name}' lists '{item}' more than once"))
+    })
+}
+
+const CREDENTIALS: &[(&str, Expect)] = &[
+    ("username", Expect::Text(any_text)),
+    ("password", Expect::Text(any_text)),
+];
+
+const FAVORITES: &[(&str, Expect)] = &[
+    ("teams", Expect::TextList(team_code)),
+    ("follow", Expect::TextList(team_code)),
+    ("color", Expect::Text(parses::<ConfigColor>)),
+    ("follow_color", Expect::Text(parses::<ConfigColor>)),
+    (
+        "colors",
+        Expect::TextMap {
+            key: team_code,
+            value: parses::<ConfigColor>,
+        },
+    ),
+    ("critical_color", Expect::Text(parses::<ConfigColor>)),
+];
+
+const DISPLAY: &[(&str, Expect)] = &[
+    ("scores", Expect::Bool),
+    ("probable_pitchers", Expect::Bool),
+    ("info_display_articles", Expect::Bool),
+    ("info_display_max_columns", Expect::Integer),
+    ("critical_inning", Expect::Integer),
+    ("critical_run_margin", Expect::Integer),
+    ("spoiler_teams", Expect::TextList(spoiler_team)),
+    ("timeformat", Expect::Text(parses::<TimeFormat>)),
+    ("timezone", Expect::Text(parses::<TimeZoneSetting>)),
+    ("columns", Expect::TextSet(parses::<Column>)),
+    ("theme", Expect::Text(parses::<ThemeSetting>)),
+    ("sort", Expect::Text(parses::<SortOrder>)),
+    ("group_by", Expect::Text(parses::<GroupBy>)),
+    ("favorites_first", Expect::Bool),
+    (
+        "linescore",
+        Expect::Deprecated("linescores aren't shown yet"),
+    ),
+    (
+        "stats_limit",
+        Expect::Deprecated("league stat leaders aren't shown yet"),
+    ),
+    (
+        "use_short_feeds",
+        Expect::Deprecated("feed names are always short"),
+    ),
+];
+
+const STREAM: &[(&str, Expect)] = &[
+    ("video_player", Expect::Player),
+    (
+        "resolution",
+        Expect::Deprecated("streams play at the player's default quality"),
+    ),
+];
+
+const PROFILE: &[(&str, Expect)] = &[
+    ("favorites", Expect::Table(FAVORITES)),
+    ("display", Expect::Table(DISPLAY)),
+    ("stream", Expect::Table(STREAM)),
+];
+
+const ROOT: &[(&str, Expect)] = &[
+    ("credentials", Expect::Table(CREDENTIALS)),
+    ("favorites", Expect::Table(FAVORITES)),
+    ("display", Expect::Table(DISPLAY)),
+    ("stream", Expect::Table(STREAM)),
+    ("profile", Expect::Named(PROFILE)),
+    ("version", Expect::Integer),
+    (
+        "streamlink",
+        Expect::Deprecated("streams go straight to stream.video_player"),
+    ),
+    (
+        "debug",
+        Expect::Deprecated("use -v, -vv or -vvv for more logging"),
+    ),
+];
+
+/// The expected value for a dotted key such as `stream.video_player`.
+pub(super) fn expect_for(key: &str) -> Option<Expect> {
+    let mut schema = ROOT;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        let &(_, expect) = schema.iter().find(|(known, _)| *known == part)?;
+        match (expect, parts.peek()) {
+            (Expect::Table(inner), Some(_)) => schema = inner,
+            // Skip the user's name, e.g. `tv` in `profile.tv.stream.video_player`.
+            (Expect::Named(inner), Some(_)) => {
+                parts.next();
+                match parts.peek() {
+                    Some(_) => schema = inner,
+                    None => return Some(Expect::Table(inner)),
+                }
+            }
+            (Expect::TextMap { key, value }, Some(entry)) => {
+                key(entry).ok()?;
+                parts.next();
+                return parts.next().is_none().then_some(Expect::Text(value));
+            }
+            (_, None) => return Some(expect),
+            (_, Some(_)) => return None,
+        }
+    }
+    None
+}
+
+/// Every deprecated key outside of profiles, with what to do instead.
+pub(super) fn deprecated_keys() -> Vec<(String, &'static str)> {
+    let mut keys = Vec::new();
+    for &(name, expect) in ROOT {
+        match expect {
+            Expect::Deprecated(reason) => keys.push((name.to_string(), reason)),
+            Expect::Table(inner) => {
+                for &(key, expect) in inner {
+                    if let Expect::Deprecated(reason) = expect {
+                        keys.push((format!("{name}.{key}"), reason));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    keys
+}
+
+/// The dotted key named by an environment variable suffix, e.g. `CREDENTIALS_USERNAME`.
+pub(super) fn key_for_env(suffix: &str) -> Option<String> {
+    let suffix = suffix.to_lowercase();
+    ROOT.iter().find_map(|&(name, expect)| match expect {
+        Expect::Table(keys) => {
+            let key = suffix.strip_prefix(name)?.strip_prefix('_')?;
+            keys.iter().find_map(|&(known, expect)| match expect {
+                // MLBV_FAVORITES_COLORS_WSH sets favorites.colors.wsh
+                Expect::TextMap { .. } => {
+                    let entry = key.strip_prefix(known)?.strip_prefix('_')?;
+                    Some(format!("{name}.{known}.{entry}"))
+                }
+                _ => (known == key).then(|| format!("{name}.{key}")),
+            })
+        }
+        _ => (suffix == name).then(|| name.to_string()),
+    })
+}
+
+struct Checker<'a> {
+    contents: &'a str,
+    player_found: &'a dyn Fn(&str) -> bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Checker<'_> {
+    fn report(&mut self, span: Option<Range<usize>>, message: impl Into<String>) {
+        self.diagnostics
+            .push(Diagnostic::at(self.contents, span, message));
+    }
+
+    fn check_table(&mut self, table: &dyn TableLike, schema: &[(&str, Expect)], path: &str) {
+        for (key, item) in table.iter() {
+            let name = match path {
+                "" => key.to_string(),
+                _ => format!("{path}.{key}"),
+            };
+            let span = table.key(key).and_then(|k| k.span());
+
+            match schema.iter().find(|(known, _)| *known == key) {
+                Some(&(_, expect)) => self.check_item(item, expect, &name),
+                None => {
+                    let suggestion = schema
+                        .iter()
+                        .map(|&(known, _)| (known, strsim::jaro_winkler(key, known)))
+                        .filter(|&(_, score)| score > 0.8)
+                        .max_by(|a, b| a.1.total_cmp(&b.1))
+                        .map(|(known, _)| format!("; did you mean '{known}'?"))
+                        .unwrap_or_default();
+                    self.report(span, format!("Unknown key '{name}'{suggestion}"));
+                }
+            }
+        }
+    }
+
+    fn check_item(&mut self, item: &Item, expect: Expect, name: &str) {
+        let span = item.span();
+        match expect {
+            Expect::Bool if item.as_bool().is_none() => {
+                self.report(span, format!("'{name}' must be true or false"));
+            }
+            Expect::Integer if item.as_integer().is_none() => {
+                self.report(span, format!("'{name}' must be a whole number"));
+            }
+            Expect::Text(validate) => match item.as_str() {
+                Some(s) => {
+                    if let Err(e) = validate(s) {
+                        self.report(span, e.to_string());
+                    }
+                }
+                None => self.report(span, format!("'{name}' must be a string")),
+            },
+            Expect::TextList(validate) | Expect::TextSet(validate) => {
+                let Some(array) = item.as_array() else {
+                    self.report(span, format!("'{name}' must be a list of strings"));
+                    return;
+                };
+                if let Expect::TextSet(_) = expect {
+                    let items: Vec<&str> = array.iter().filter_map(|v| v.as_str()).collect();
+                    if let Some(problem) = set_problem(name, &items) {
+                        self.report(span, problem);
+                    }
+                }
+                for value in array.iter() {
+                    match value.as_str() {
+                        Some(s) => {
+                            if let Err(e) = validate(s) {
+                                self.report(value.span(), e.to_string());
+                            }
+                        }
+                        None => self.report(value.span(), format!("'{name}' must hold strings")),
+                    }
+                }
+            }
+            Expect::Player => match item.as_str() {
+                Some(player) if !player.is_empty() && !(self.player_found)(player) => {
+                    self.report(
+                        span,
+                        format!(
+                            "Media player '{player}' was not found; \
+                             the system default player will be used instead"
+                        ),
+                    );
+                }
+                Some(_) => {}
+                None => self.report(span, format!("'{name}' must be a string")),
+            },
+            Expect::Table(schema) => match item.as_table_like() {
+                Some(table) => self.check_table(table, schema, name),
+                None => self.report(span, format!("'{name}' must be a table")),
+            },
+            Expect::TextMap { key, value } => match item.as_table_like() {
+                Some(table) => {
+                    for (entry, item) in table.iter() {
+                        let name = format!("{name}.{entry}");
+                        match key(entry) {
+                            Ok(()) => self.check_item(item, Expect::Text(value), &name),
+                            Err(e) => self.report(item.span(), format!("'{name}': {e}")),
+                        }
+                    }
+                }
+                None => self.report(span, format!("'{name}' must be a table")),
+            },
+            Expect::Named(schema) => match item.as_table_like() {
+                Some(table) => {
+                    for (entry, item) in table.iter() {
+                        self.check_item(item, Expect::Table(schema), &format!("{name}.{entry}"));
+                    }
+                }
+                None => self.report(span, format!("'{name}' must be a table")),
+            },
+            Expect::Deprecated(reason) => {
+                self.report(span, format!("'{name}' is no longer used: {reason}"));
+            }
+            Expect::Bool | Expect::Integer => {}
+        }
+    }
+}
+
+/// Check every key in a config file's contents. `player_found` decides whether a
+/// `video_player` value resolves to something runnable.
+pub fn validate(contents: &str, player_found: &dyn Fn(&str) -> bool) -> Vec<Diagnostic> {
+    let doc = match ImDocument::parse(contents) {
+        Ok(doc) => doc,
+        Err(e) => return vec![Diagnostic::at(contents, e.span(), e.message().trim_end())],
+    };
+
+    let mut checker = Checker {
+        contents,
+        player_found,
+        diagnostics: Vec::new(),
+    };
+    checker.check_table(doc.as_table(), ROOT, "");
+
+    // Catch anything the key checks can't see, such as a missing [credentials] table
+    // or numbers out of range.
+    if checker.diagnostics.is_empty()
+        && let Err(e) = toml::from_str::<super::AppConfig>(contents)
+    {
+        checker
+            .diagnostics
+            .push(Diagnostic::from_toml_error(contents, &e));
+    }
+
+    checker.diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(contents: &str) -> Vec<Diagnostic> {
+        validate(contents, &|_| true)
+    }
+
+    #[test]
+    fn template_passes_validation() {
+        assert_eq!(check(super::super::config::TEMPLATE), Vec::new());
+    }
+
+    #[test]
+    fn reports_unknown_keys_and_bad_values_with_positions() {
+        let contents = "[credentials]\nusername = \"\"\npassword = \"\"\n\n\
+                        [favorites]\nteams = [\"wsh\", \"xyz\"]\ncolour = \"red\"\n";
+        let diagnostics = check(contents);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (6, 17));
+        assert!(diagnostics[0].message.contains("xyz"));
+        assert_eq!((diagnostics[1].line, diagnostics[1].column), (7, 1));
+        assert!(diagnostics[1].message.contains("did you mean 'color'"));
+    }
+
+    #[test]
+    fn reports_unknown_colors_and_missing_players() {
+        let contents = "[credentials]\nusername = \"\"\npassword = \"\"\n\
+                        [favorites]\nteams = []\ncolor = \"chartreuse\"\n\
+                        [stream]\nvideo_player = \"not-a-player\"\n";
+        let diagnostics = validate(contents, &|player| player == "mpv");
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].message.contains("Unknown color: chartreuse"));
+        assert_eq!(diagnostics[1].line, 8);
+    }
+
+    #[test]
+    fn checks_profile_sections_against_their_tables() {
+        let contents = "[credentials]\nusername = \"\"\npassword = \"\"\n\
+                        [profile.tv.stream]\nvideo_player = \"vlc\"\n\
+                        [profile.laptop]\ncredentials.username = \"x\"\n";
+        let diagnostics = check(contents);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 7);
+        assert!(
+            diagnostics[0]
+                .message
+                .contains("profile.laptop.credentials")
+        );
+    }
+
+    #[test]
+    fn team_colors_are_keyed_by_team_code() {
+        let contents = "[credentials]\nusername = \"\"\npassword = \"\"\n\
+                        [favorites]\nteams = []\n[favorites.colors]\nwsh = \"red\"\n\
+                        xyz = \"red\"\ntor = \"plaid\"\n";
+        let diagnostics = check(contents);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!((diagnostics[0].line, diagnostics[1].line), (8, 9));
+
+        assert!(matches!(
+            expect_for("favorites.colors.wsh"),
+            Some(Expect::Text(_))
+        ));
+        assert!(expect_for("favorites.colors.xyz").is_none());
+        assert_eq!(
+            key_for_env("FAVORITES_COLORS_WSH").as_deref(),
+            Some("favorites.colors.wsh")
+        );
+    }
+
+    #[test]
+    fn rejects_empty_and_repeated_columns() {
+        let diagnostics = check("[display]\ncolumns = []\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("needs at least one entry"));
+
+        let diagnostics = check("[display]\ncolumns = [\"score\", \"tv\", \"Score\"]\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "'display.columns' lists 'Score' more than once"
+        );
+    }
+
+    #[test]
+    fn reports_deprecated_keys() {
+        let contents = "debug = true\n[credentials]\nusername = \"\"\npassword = \"\"\n";
+        let diagnostics = check(contents);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("'debug' is no longer used"));
+    }
+
+    #[test]
+    fn reports_syntax_errors_with_a_line() {
+        let diagnostics = check("[display]\nscores = yes\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 2);
+    }
+}
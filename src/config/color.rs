@@ -0,0 +1,292 @@
+use anyhow::Result;
+use serde::Deserialize;
+use std::env;
+use std::str::FromStr;
+use tabled::settings::Color;
+
+use crate::data::teamdata::{TeamCode, TeamColor};
+
+/// A terminal color: one of the 16 ANSI colors, a 256-color palette index, or RGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Paint {
+    Ansi(u8),
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+/// Whether a color applies to the text, to dimmed text or to the cell behind it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorStyle {
+    #[default]
+    Foreground,
+    Dim,
+    Background,
+}
+
+/// Color names accepted in the config, including those the Python mlbv understood.
+const NAMED_COLORS: &[(&str, Paint)] = &[
+    ("black", Paint::Ansi(0)),
+    ("red", Paint::Ansi(1)),
+    ("green", Paint::Ansi(2)),
+    ("yellow", Paint::Ansi(3)),
+    ("blue", Paint::Ansi(4)),
+    ("magenta", Paint::Ansi(5)),
+    ("purple", Paint::Ansi(5)),
+    ("cyan", Paint::Ansi(6)),
+    ("white", Paint::Ansi(7)),
+    ("lightgrey", Paint::Ansi(7)),
+    ("lightgray", Paint::Ansi(7)),
+    ("darkgrey", Paint::Ansi(8)),
+    ("darkgray", Paint::Ansi(8)),
+    ("grey", Paint::Ansi(8)),
+    ("gray", Paint::Ansi(8)),
+    ("lightred", Paint::Ansi(9)),
+    ("lightgreen", Paint::Ansi(10)),
+    ("lightyellow", Paint::Ansi(11)),
+    ("lightblue", Paint::Ansi(12)),
+    ("pink", Paint::Ansi(13)),
+    ("lightmagenta", Paint::Ansi(13)),
+    ("lightpurple", Paint::Ansi(13)),
+    ("lightcyan", Paint::Ansi(14)),
+    ("brightwhite", Paint::Ansi(15)),
+    // No orange among the ANSI colors
+    ("orange", Paint::Indexed(208)),
+];
+
+/// Whether the terminal advertises 24-bit color; otherwise RGB falls back to 256 colors.
+fn truecolor() -> bool {
+    env::var("COLORTERM").is_ok_and(|v| v == "truecolor" || v == "24bit")
+        || env::var_os("WT_SESSION").is_some()
+}
+
+/// The nearest entry in the 256-color palette's 6x6x6 cube or grayscale ramp.
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            249.. => 231,
+            _ => 232 + ((r - 8) as u16 * 24 / 241) as u8,
+        };
+    }
+    let level = |c: u8| match c {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (c - 35) / 40,
+    };
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+impl Paint {
+    fn parse(s: &str) -> Option<Self> {
+        if let Some(hex) = s.strip_prefix('#') {
+            if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            let rgb = u32::from_str_radix(hex, 16).ok()?;
+            let [_, r, g, b] = rgb.to_be_bytes();
+            return Some(Paint::Rgb(r, g, b));
+        }
+        if s.bytes().all(|b| b.is_ascii_digit()) {
+            return s.parse().ok().map(Paint::Indexed);
+        }
+        NAMED_COLORS
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|&(_, paint)| paint)
+    }
+
+    pub fn to_tabled_color(self, style: ColorStyle) -> Color {
+        let (select, reset) = match style {
+            ColorStyle::Foreground => (38, "\u{1b}[39m"),
+            ColorStyle::Dim => (38, "\u{1b}[39;22m"),
+            ColorStyle::Background => (48, "\u{1b}[49m"),
+        };
+        let prefix = match self {
+            Paint::Ansi(n @ 0..8) => format!("\u{1b}[{}m", select - 8 + n),
+            Paint::Ansi(n) => format!("\u{1b}[{}m", select + 52 + (n & 7)),
+            Paint::Indexed(n) => format!("\u{1b}[{select};5;{n}m"),
+            Paint::Rgb(r, g, b) if truecolor() => format!("\u{1b}[{select};2;{r};{g};{b}m"),
+            Paint::Rgb(r, g, b) => format!("\u{1b}[{select};5;{}m", nearest_indexed(r, g, b)),
+        };
+        match style {
+            ColorStyle::Dim => Color::new(format!("\u{1b}[2m{prefix}"), reset),
+            _ => Color::new(prefix, reset),
+        }
+    }
+}
+
+impl From<TeamColor> for Paint {
+    fn from(color: TeamColor) -> Self {
+        Paint::Rgb(color.r, color.g, color.b)
+    }
+}
+
+/// A color setting such as `red`, `#FF6600`, `208`, `team`, `dim:team` or `bg:team`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum ConfigColor {
+    /// Highlighting turned off, from an empty value.
+    Off,
+    /// Each team's own color.
+    Team {
+        secondary: bool,
+        style: ColorStyle,
+    },
+    Fixed {
+        paint: Paint,
+        style: ColorStyle,
+    },
+}
+
+impl Default for ConfigColor {
+    fn default() -> Self {
+        ConfigColor::Team {
+            secondary: false,
+            style: ColorStyle::Foreground,
+        }
+    }
+}
+
+impl ConfigColor {
+    pub fn to_tabled_color(&self, team_code: Option<TeamCode>) -> Option<Color> {
+        match *self {
+            ConfigColor::Off => None,
+            ConfigColor::Team { secondary, style } => {
+                let team = team_code?.team();
+                let color = match secondary {
+                    true => team.secondary_color,
+                    false => team.primary_color,
+                };
+                // Dark team colors make fine backgrounds but unreadable text.
+                let color = match style {
+                    ColorStyle::Background => color,
+                    _ => color.readable(),
+                };
+                Some(Paint::from(color).to_tabled_color(style))
+            }
+            ConfigColor::Fixed { paint, style } => Some(paint.to_tabled_color(style)),
+        }
+    }
+}
+
+impl FromStr for ConfigColor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        if s.is_empty() {
+            return Ok(ConfigColor::Off);
+        }
+        let (style, name) = match s.split_once(':') {
+            Some(("bg", name)) => (ColorStyle::Background, name),
+            Some(("fg", name)) => (ColorStyle::Foreground, name),
+            Some(("dim", name)) => (ColorStyle::Dim, name),
+            _ => (ColorStyle::Foreground, s.as_str()),
+        };
+        match name {
+            "team" => Ok(ConfigColor::Team {
+                secondary: false,
+                style,
+            }),
+            "team-secondary" => Ok(ConfigColor::Team {
+                secondary: true,
+                style,
+            }),
+            _ => match Paint::parse(name) {
+                Some(paint) => Ok(ConfigColor::Fixed { paint, style }),
+                None => anyhow::bail!(
+                    "Unknown color: {s}; expected 'team', 'team-secondary', a color name, \
+                     a #RRGGBB value or a 256-color number, optionally prefixed with 'bg:' or 'dim:'"
+                ),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for ConfigColor {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        ConfigColor::from_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_names_hex_indexes_and_styles() {
+        let fixed = |s: &str| match ConfigColor::from_str(s).unwrap() {
+            ConfigColor::Fixed { paint, style } => (paint, style),
+            other => panic!("{s} parsed as {other:?}"),
+        };
+        assert_eq!(fixed("Orange").0, Paint::Indexed(208));
+        assert_eq!(fixed("lightcyan").0, Paint::Ansi(14));
+        assert_eq!(fixed("#AB0003").0, Paint::Rgb(0xAB, 0x00, 0x03));
+        assert_eq!(fixed("bg:33"), (Paint::Indexed(33), ColorStyle::Background));
+
+        assert_eq!(ConfigColor::from_str("").unwrap(), ConfigColor::Off);
+        assert_eq!(
+            ConfigColor::from_str("bg:team").unwrap(),
+            ConfigColor::Team {
+                secondary: false,
+                style: ColorStyle::Background
+            }
+        );
+        for bad in ["chartreuse", "#12345", "256", "bg:"] {
+            assert!(ConfigColor::from_str(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn writes_ansi_sequences_for_each_style() {
+        let prefix = |paint: Paint, style| paint.to_tabled_color(style).get_prefix().to_string();
+        assert_eq!(prefix(Paint::Ansi(3), ColorStyle::Foreground), "\u{1b}[33m");
+        assert_eq!(
+            prefix(Paint::Ansi(12), ColorStyle::Foreground),
+            "\u{1b}[94m"
+        );
+        assert_eq!(prefix(Paint::Ansi(1), ColorStyle::Background), "\u{1b}[41m");
+        assert_eq!(
+            prefix(Paint::Ansi(9), ColorStyle::Background),
+            "\u{1b}[101m"
+        );
+        assert_eq!(
+            prefix(Paint::Ansi(2), ColorStyle::Dim),
+            "\u{1b}[2m\u{1b}[32m"
+        );
+        assert_eq!(
+            prefix(Paint::Indexed(208), ColorStyle::Background),
+            "\u{1b}[48;5;208m"
+        );
+    }
+
+    #[test]
+    fn dark_team_colors_are_lightened_only_for_text() {
+        let navy = TeamColor::hex(0x0C2340);
+        let readable = navy.readable();
+        assert!(readable.r > navy.r && readable.g > navy.g && readable.b > navy.b);
+        assert!(readable.b > readable.r, "keeps its hue");
+        assert_eq!(
+            TeamColor::hex(0xFD5A1E).readable(),
+            TeamColor::hex(0xFD5A1E)
+        );
+
+        let nyy = Some(TeamCode::Nyy);
+        let text = ConfigColor::default().to_tabled_color(nyy).unwrap();
+        let cell = ConfigColor::from_str("bg:team")
+            .unwrap()
+            .to_tabled_color(nyy);
+        assert_ne!(text.get_prefix(), cell.unwrap().get_prefix());
+        assert!(ConfigColor::default().to_tabled_color(None).is_none());
+    }
+
+    #[test]
+    fn rgb_falls_back_to_the_nearest_palette_entry() {
+        assert_eq!(nearest_indexed(0, 0, 0), 16);
+        assert_eq!(nearest_indexed(255, 255, 255), 231);
+        assert_eq!(nearest_indexed(255, 0, 0), 196);
+        assert_eq!(nearest_indexed(0x0C, 0x23, 0x40), 17);
+    }
+}
@@ -1,13 +1,23 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use directories::ProjectDirs;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
 use std::fs;
 use std::io::{self, IsTerminal, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use tabled::settings::Color;
 
-use crate::data::teamdata::TeamCode;
+use super::check::{self, Diagnostic};
+use super::color::{ColorStyle, ConfigColor, Paint};
+use super::edit;
+use super::migrate;
+use super::overrides::{self, ENV_PREFIX};
+use crate::data::teamdata::{Team, TeamCode};
+
+/// The commented default config written by `--init`.
+pub const TEMPLATE: &str = include_str!("template.toml");
 
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
@@ -26,111 +36,422 @@ pub struct AppConfig {
 #[derive(Debug, Deserialize)]
 pub struct Favorites {
     pub teams: Vec<TeamCode>,
+    /// A second tier of teams, highlighted less prominently than favorites.
+    #[serde(default)]
+    pub follow: Vec<TeamCode>,
 
     #[serde(default)]
     pub color: ConfigColor,
-    // pub critical_color: ConfigColor,
+    #[serde(default = "default_follow_color")]
+    pub follow_color: ConfigColor,
+    /// Per-team colors from `[favorites.colors]`, overriding the tier colors.
+    #[serde(default)]
+    pub colors: HashMap<TeamCode, ConfigColor>,
+    #[serde(default = "default_critical_color")]
+    pub critical_color: ConfigColor,
+}
+
+fn default_follow_color() -> ConfigColor {
+    ConfigColor::Team {
+        secondary: false,
+        style: ColorStyle::Dim,
+    }
+}
+
+fn default_critical_color() -> ConfigColor {
+    ConfigColor::Fixed {
+        paint: Paint::Ansi(3),
+        style: ColorStyle::Foreground,
+    }
 }
 
 impl Default for Favorites {
     fn default() -> Self {
         Self {
             teams: Vec::new(), // Won't match any teams by default
-            color: ConfigColor::TeamColors,
-            // critical_color: ConfigColor::Named("yellow".to_string()),
+            follow: Vec::new(),
+            color: ConfigColor::default(),
+            follow_color: default_follow_color(),
+            colors: HashMap::new(),
+            critical_color: default_critical_color(),
         }
     }
 }
 
-#[derive(Debug, Default, Deserialize)]
+impl Favorites {
+    /// The row color for a game between the teams with these ids. A favorite playing
+    /// wins over a followed team, and earlier teams in each list win over later ones.
+    pub fn color_for(&self, team_ids: [u32; 2]) -> Option<Color> {
+        let playing = |code: &&TeamCode| team_ids.contains(&code.team().id);
+        let (code, tier_color) = match self.teams.iter().find(playing) {
+            Some(code) => (*code, &self.color),
+            None => (*self.follow.iter().find(playing)?, &self.follow_color),
+        };
+        self.colors
+            .get(&code)
+            .unwrap_or(tier_color)
+            .to_tabled_color(Some(code))
+    }
+
+    /// The critical-game color for a game between the teams with these ids. Team
+    /// colors follow the favorite or followed team playing, or else the home team.
+    pub fn critical_color_for(&self, team_ids: [u32; 2]) -> Option<Color> {
+        let playing = |code: &&TeamCode| team_ids.contains(&code.team().id);
+        let code = self.teams.iter().chain(&self.follow).find(playing).copied();
+        let code = code.or_else(|| {
+            let [away, home] = team_ids;
+            Team::try_find_by_id(&home)
+                .or_else(|| Team::try_find_by_id(&away))
+                .map(|team| team.code)
+        });
+        self.critical_color.to_tabled_color(code)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
 pub struct Display {
     pub scores: bool,
+    pub probable_pitchers: bool,
+    pub info_display_articles: bool,
+    pub info_display_max_columns: usize,
+    pub critical_inning: u8,
+    pub critical_run_margin: u8,
+    pub spoiler_teams: Vec<SpoilerTeam>,
+    pub timeformat: Option<TimeFormat>,
+    pub timezone: TimeZoneSetting,
+    #[serde(deserialize_with = "unique_columns")]
+    pub columns: Vec<Column>,
+    pub theme: ThemeSetting,
+    pub sort: SortOrder,
+    pub group_by: GroupBy,
+    pub favorites_first: bool,
     // pub linescore: bool,
-    // pub timeformat: String,
     // pub stats_limit: u32,
 }
 
-// impl Default for Display {
-//     fn default() -> Self {
-//         Self {
-//             scores: false,
-//             // linescore: true,
-//             // timeformat: "%I:%M %p".to_string(),
-//             // stats_limit: 5,
-//         }
-//     }
-// }
+impl Default for Display {
+    fn default() -> Self {
+        Self {
+            scores: false,
+            probable_pitchers: false,
+            info_display_articles: true,
+            info_display_max_columns: 110,
+            critical_inning: 7,
+            critical_run_margin: 1,
+            spoiler_teams: Vec::new(),
+            timeformat: None, // Picked per display mode
+            timezone: TimeZoneSetting::Local,
+            columns: DEFAULT_COLUMNS.to_vec(),
+            theme: ThemeSetting::Auto,
+            sort: SortOrder::Time,
+            group_by: GroupBy::None,
+            favorites_first: false,
+            // linescore: true,
+            // stats_limit: 5,
+        }
+    }
+}
 
-#[derive(Debug, Deserialize)]
-pub struct Stream {
-    // pub resolution: String,
-    pub video_player: String,
+/// A team whose results stay hidden even when scores are shown.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(try_from = "String")]
+pub enum SpoilerTeam {
+    Favorites,
+    Team(TeamCode),
 }
 
-impl Default for Stream {
-    fn default() -> Self {
-        Self {
-            // resolution: "1080p".to_string(),
-            video_player: "mpv".to_string(),
+impl TryFrom<String> for SpoilerTeam {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match s.to_lowercase().as_str() {
+            "favorites" | "favs" => Ok(SpoilerTeam::Favorites),
+            _ => Ok(SpoilerTeam::Team(TeamCode::from_str(&s)?)),
         }
     }
 }
 
-// #[derive(Debug, Deserialize)]
-// pub struct Streamlink {
-//     // include streamlink-related keys as Option<T> here
-//     // e.g. pub streamlink_highlights: Option<bool>,
-// }
+/// Table borders and feed labels: Unicode box drawing and emoji, or plain ASCII.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum ThemeSetting {
+    #[default]
+    Auto,
+    Unicode,
+    Ascii,
+}
 
-#[derive(Debug, Deserialize)]
-pub struct Credentials {
-    pub username: String,
-    pub password: String,
+impl FromStr for ThemeSetting {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(ThemeSetting::Auto),
+            "unicode" => Ok(ThemeSetting::Unicode),
+            "ascii" => Ok(ThemeSetting::Ascii),
+            _ => anyhow::bail!("Invalid theme: {s}; expected 'auto', 'unicode' or 'ascii'"),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+impl TryFrom<String> for ThemeSetting {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        ThemeSetting::from_str(&s)
+    }
+}
+
+/// Order of games within a schedule table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
 #[serde(try_from = "String")]
-pub enum ConfigColor {
-    Named(String),
+pub enum SortOrder {
     #[default]
-    TeamColors,
+    Time,
+    Status,
+    Closeness,
 }
 
-impl ConfigColor {
-    pub fn to_tabled_color(&self, team_code: Option<TeamCode>) -> Option<Color> {
-        match self {
-            ConfigColor::Named(name) => parse_named_color(name),
-            ConfigColor::TeamColors => {
-                team_code.map(|code| code.team().primary_color.to_tabled_color())
-            }
+impl FromStr for SortOrder {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "time" => Ok(SortOrder::Time),
+            "status" | "state" => Ok(SortOrder::Status),
+            "closeness" | "close" => Ok(SortOrder::Closeness),
+            _ => anyhow::bail!("Invalid sort order: {s}; expected 'time', 'status' or 'closeness'"),
         }
     }
 }
 
-impl FromStr for ConfigColor {
+impl TryFrom<String> for SortOrder {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        SortOrder::from_str(&s)
+    }
+}
+
+/// How a day's games are split into separate tables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum GroupBy {
+    #[default]
+    None,
+    Favorites,
+    Division,
+    Time,
+}
+
+impl FromStr for GroupBy {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.eq_ignore_ascii_case("team") {
-            Ok(ConfigColor::TeamColors)
-        } else {
-            Ok(ConfigColor::Named(s.to_string()))
+        match s.to_lowercase().as_str() {
+            "none" => Ok(GroupBy::None),
+            "favorites" | "favs" => Ok(GroupBy::Favorites),
+            "division" => Ok(GroupBy::Division),
+            "time" => Ok(GroupBy::Time),
+            _ => anyhow::bail!(
+                "Invalid grouping: {s}; expected 'none', 'favorites', 'division' or 'time'"
+            ),
+        }
+    }
+}
+
+impl TryFrom<String> for GroupBy {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        GroupBy::from_str(&s)
+    }
+}
+
+/// A schedule table column, shown in the order listed in `[display] columns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Column {
+    Matchup,
+    Series,
+    Score,
+    State,
+    Feeds,
+    Highlights,
+    Pitchers,
+    Venue,
+    Tv,
+    Records,
+    Weather,
+}
+
+pub const DEFAULT_COLUMNS: [Column; 6] = [
+    Column::Matchup,
+    Column::Series,
+    Column::Score,
+    Column::State,
+    Column::Feeds,
+    Column::Highlights,
+];
+
+impl FromStr for Column {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "matchup" => Ok(Column::Matchup),
+            "series" => Ok(Column::Series),
+            "score" => Ok(Column::Score),
+            "state" => Ok(Column::State),
+            "feeds" => Ok(Column::Feeds),
+            "highlights" => Ok(Column::Highlights),
+            "pitchers" => Ok(Column::Pitchers),
+            "venue" => Ok(Column::Venue),
+            "tv" => Ok(Column::Tv),
+            "records" => Ok(Column::Records),
+            "weather" => Ok(Column::Weather),
+            _ => anyhow::bail!(
+                "Invalid column: {s}; expected one of matchup, series, score, state, feeds, \
+                 highlights, pitchers, venue, tv, records, weather"
+            ),
+        }
+    }
+}
+
+impl TryFrom<String> for Column {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Column::from_str(&s)
+    }
+}
+
+/// An empty list would leave an empty table, and a repeated column breaks the widths.
+fn unique_columns<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Column>, D::Error> {
+    let columns = Vec::<Column>::deserialize(deserializer)?;
+    if columns.is_empty() {
+        return Err(serde::de::Error::custom(
+            "columns needs at least one column",
+        ));
+    }
+    for (idx, column) in columns.iter().enumerate() {
+        if columns[..idx].contains(column) {
+            return Err(serde::de::Error::custom(format!(
+                "Column '{}' is listed more than once",
+                format!("{column:?}").to_lowercase()
+            )));
+        }
+    }
+    Ok(columns)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum TimeFormat {
+    Hour12,
+    Hour24,
+}
+
+impl FromStr for TimeFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "12h" | "12" => Ok(TimeFormat::Hour12),
+            "24h" | "24" => Ok(TimeFormat::Hour24),
+            _ => anyhow::bail!("Invalid time format: {s}; expected '12H' or '24H'"),
         }
     }
 }
 
-impl TryFrom<String> for ConfigColor {
+impl TryFrom<String> for TimeFormat {
     type Error = anyhow::Error;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
-        ConfigColor::from_str(&s)
+        TimeFormat::from_str(&s)
     }
 }
 
+/// Time zone used for game start times.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum TimeZoneSetting {
+    #[default]
+    Local,
+    Ballpark,
+    Named(chrono_tz::Tz),
+}
+
+impl FromStr for TimeZoneSetting {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(TimeZoneSetting::Local),
+            "ballpark" | "venue" => Ok(TimeZoneSetting::Ballpark),
+            _ => s.parse::<chrono_tz::Tz>().map(TimeZoneSetting::Named).map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid time zone: {s}; expected 'local', 'ballpark' or a name like 'America/New_York'"
+                )
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for TimeZoneSetting {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        TimeZoneSetting::from_str(&s)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Stream {
+    // pub resolution: String,
+    pub video_player: String,
+}
+
+impl Default for Stream {
+    fn default() -> Self {
+        Self {
+            // resolution: "1080p".to_string(),
+            video_player: "mpv".to_string(),
+        }
+    }
+}
+
+// #[derive(Debug, Deserialize)]
+// pub struct Streamlink {
+//     // include streamlink-related keys as Option<T> here
+//     // e.g. pub streamlink_highlights: Option<bool>,
+// }
+
+#[derive(Debug, Deserialize)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
 pub fn project_dirs() -> ProjectDirs {
     ProjectDirs::from("", "", "mlbv-rs").expect("Could not resolve project directory.")
 }
 
+pub fn config_path() -> PathBuf {
+    project_dirs().config_dir().join("config.toml")
+}
+
+fn positioned_error(config_file: &Path, contents: &str, err: toml::de::Error) -> anyhow::Error {
+    let diagnostic = Diagnostic::from_toml_error(contents, &err);
+    anyhow::anyhow!(
+        "{}:{diagnostic}\nRun `mlbv config check` to list every problem.",
+        config_file.display()
+    )
+}
+
 impl AppConfig {
     fn prompt_credential(label: &str) -> io::Result<String> {
         if label.eq_ignore_ascii_case("password") {
@@ -148,73 +469,390 @@ impl AppConfig {
         }
     }
 
-    pub fn generate_config() -> Result<()> {
-        let config_dir = project_dirs().config_dir().to_path_buf();
-        let config_file = config_dir.join("config.toml");
-        fs::create_dir_all(config_dir)?;
-
-        let template_path = PathBuf::from("src/config/template.toml");
-        if !template_path.exists() {
-            anyhow::bail!("Template file not found at {}", template_path.display());
+    /// Write the template with credentials filled in, or update just the credentials
+    /// when the config file already exists. Credentials not passed in are prompted
+    /// for, which needs an interactive terminal.
+    pub fn generate_config(
+        path: Option<&Path>,
+        username: Option<&str>,
+        password_stdin: bool,
+    ) -> Result<()> {
+        let config_file = path.map_or_else(config_path, Path::to_path_buf);
+        if let Some(config_dir) = config_file.parent() {
+            fs::create_dir_all(config_dir)?;
         }
 
-        // Interactive terminal required for credential input
-        if !io::stdin().is_terminal() {
+        // Interactive terminal required for credential prompts
+        if (username.is_none() || !password_stdin) && !io::stdin().is_terminal() {
             anyhow::bail!(
                 "Cannot run --init in non-interactive mode.\n\
-                 Please run this command in an interactive terminal, or manually create:\n\
+                 Please run this command in an interactive terminal, pass \
+                 --username NAME --password-stdin, or manually create:\n\
                  {:#?}",
                 config_file
             );
         }
 
-        // Read the template into a string and prompt user for credentials
-        let contents = fs::read_to_string(&template_path)?;
-        let username = Self::prompt_credential("username")?;
-        let password = Self::prompt_credential("password")?;
-
-        // Parse template and update credentials while preserving comments/format
-        let mut doc: toml_edit::DocumentMut = contents.parse()?;
-        doc["credentials"]["username"] = toml_edit::value(username);
-        doc["credentials"]["password"] = toml_edit::value(password);
+        let username = match username {
+            Some(username) => username.to_string(),
+            None => Self::prompt_credential("username")?,
+        };
+        let password = match password_stdin {
+            true => {
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
+                input.trim_end_matches(['\r', '\n']).to_string()
+            }
+            false => Self::prompt_credential("password")?,
+        };
 
-        fs::write(config_file, doc.to_string())?;
+        let existing = match config_file.exists() {
+            true => Some(fs::read_to_string(&config_file)?),
+            false => None,
+        };
+        let contents = Self::with_credentials(existing.as_deref(), &username, &password)
+            .with_context(|| format!("Failed to update {}", config_file.display()))?;
+        fs::write(config_file, contents)?;
 
         Ok(())
     }
 
-    pub fn load() -> Result<Self> {
-        let config_dir = project_dirs().config_dir().to_path_buf();
-        let config_file = config_dir.join("config.toml");
+    /// Fill in the credentials in `existing`, or in the template when there's no config
+    /// file yet, preserving comments and every other setting.
+    fn with_credentials(existing: Option<&str>, username: &str, password: &str) -> Result<String> {
+        if username.is_empty() || password.is_empty() {
+            anyhow::bail!("The mlb.tv username and password can't be empty");
+        }
+        let mut doc: toml_edit::DocumentMut = existing.unwrap_or(TEMPLATE).parse()?;
+        edit::set_in_document(&mut doc, "credentials.username", username)?;
+        edit::set_in_document(&mut doc, "credentials.password", password)?;
+        Ok(doc.to_string())
+    }
+
+    /// Apply the selected profile, `MLBV_*` environment variables and `--set` overrides.
+    fn apply_layers(table: &mut toml::Table, sources: &ConfigSources) -> Result<()> {
+        if let Some(profile) = &sources.profile {
+            overrides::apply_profile(table, profile)?;
+        }
+        overrides::apply_env(table, overrides::mlbv_vars(env::vars_os()))?;
+        overrides::apply_settings(table, &sources.settings)
+    }
+
+    /// Read the config file, upgrading it in place to the current version first and
+    /// warning about deprecated keys. The original is kept next to it as e.g.
+    /// `config.toml.v0.bak`; an existing backup is never overwritten.
+    fn read_upgraded(config_file: &Path) -> Result<String> {
+        let contents = fs::read_to_string(config_file)?;
+        let contents = match migrate::migrate(&contents)
+            .with_context(|| format!("Failed to upgrade {}", config_file.display()))?
+        {
+            Some(migration) => Self::save_upgraded(config_file, &contents, migration),
+            None => contents,
+        };
+        for note in migrate::deprecated_notes(&contents) {
+            tracing::warn!("{}: {note}", config_file.display());
+        }
+        Ok(contents)
+    }
+
+    fn save_upgraded(config_file: &Path, original: &str, migration: migrate::Migration) -> String {
+        for note in &migration.notes {
+            tracing::warn!("{}: {note}", config_file.display());
+        }
+        let backup = config_file.with_extension(format!("toml.v{}.bak", migration.from_version));
+        let saved = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&backup)
+            .and_then(|mut file| file.write_all(original.as_bytes()))
+            .and_then(|()| fs::write(config_file, &migration.contents));
+        match saved {
+            Ok(()) => tracing::warn!(
+                "Upgraded {} to config version {}; the original is saved as {}",
+                config_file.display(),
+                migrate::CONFIG_VERSION,
+                backup.display()
+            ),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => tracing::warn!(
+                "Not upgrading {}: {} already exists; move it aside to upgrade",
+                config_file.display(),
+                backup.display()
+            ),
+            Err(e) => tracing::warn!("Couldn't save the upgraded {}: {e}", config_file.display()),
+        }
+        migration.contents
+    }
+
+    /// Convert a Python mlbv config into a new `config.toml`, leaving the original alone.
+    fn import_python(python_config: &Path, config_file: &Path) -> Result<String> {
+        let contents = fs::read_to_string(python_config)?;
+        let migration = migrate::migrate(&contents)?
+            .with_context(|| format!("{} isn't a Python mlbv config", python_config.display()))?;
+        for note in &migration.notes {
+            tracing::warn!("{}: {note}", python_config.display());
+        }
+        if let Some(config_dir) = config_file.parent() {
+            fs::create_dir_all(config_dir)?;
+        }
+        fs::write(config_file, &migration.contents)
+            .with_context(|| format!("Failed to write {}", config_file.display()))?;
+        Ok(migration.contents)
+    }
+
+    /// Layer the config file, the selected profile, `MLBV_*` environment variables and
+    /// `--set` overrides over the built-in defaults.
+    pub fn load(sources: &ConfigSources) -> Result<Self> {
+        let config_file = sources.config_file();
 
-        // ensure the config exists (creates from template if needed)
-        if !config_file.exists() {
+        let python_config = migrate::python_config_path().filter(|path| path.exists());
+
+        let contents = if config_file.exists() {
+            tracing::debug!("Loading config from: {}", config_file.display());
+            Some(Self::read_upgraded(&config_file)?)
+        } else if sources.path.is_some() {
+            anyhow::bail!("Config file not found: {}", config_file.display());
+        } else if let Some(python_config) = python_config {
+            println!(
+                "Importing settings from {} into {}",
+                python_config.display(),
+                config_file.display()
+            );
+            Some(Self::import_python(&python_config, &config_file)?)
+        } else if io::stdin().is_terminal() {
+            // Create the default config from the template on first run
             println!(
                 "Config file not found, creating from template at {}",
                 config_file.display()
             );
-            Self::generate_config()?;
+            Self::generate_config(None, None, false)?;
+            Some(fs::read_to_string(&config_file)?)
+        } else {
+            // Headless setups can run from environment variables alone.
+            tracing::info!("No config file; using defaults and {ENV_PREFIX}* variables");
+            None
+        };
+
+        let Some(contents) = contents else {
+            let mut table = toml::Table::new();
+            Self::apply_layers(&mut table, sources)?;
+            return toml::Value::Table(table).try_into().with_context(|| {
+                format!(
+                    "No config file at {}; run --init or set \
+                     {ENV_PREFIX}CREDENTIALS_USERNAME and {ENV_PREFIX}CREDENTIALS_PASSWORD",
+                    config_file.display()
+                )
+            });
+        };
+
+        let positioned = |e| positioned_error(&config_file, &contents, e);
+        let mut table: toml::Table = toml::from_str(&contents).map_err(positioned)?;
+        Self::apply_layers(&mut table, sources)?;
+
+        toml::Value::Table(table).try_into().map_err(|e| {
+            // Point at the file when the problem is in it, rather than in an override.
+            match toml::from_str::<AppConfig>(&contents) {
+                Err(file_err) if file_err.span().is_some() => positioned(file_err),
+                _ => anyhow::Error::new(e).context("Invalid configuration"),
+            }
+        })
+    }
+
+    /// Every layered setting as TOML, without defaults filled in or a file created.
+    pub fn layered_table(sources: &ConfigSources) -> Result<toml::Table> {
+        let config_file = sources.config_file();
+        let mut table = if config_file.exists() {
+            let contents = fs::read_to_string(&config_file)?;
+            toml::from_str(&contents).map_err(|e| positioned_error(&config_file, &contents, e))?
+        } else if sources.path.is_some() {
+            anyhow::bail!("Config file not found: {}", config_file.display());
+        } else {
+            toml::Table::new()
+        };
+        Self::apply_layers(&mut table, sources)?;
+        Ok(table)
+    }
+
+    /// Validate the config file and print each problem with its line and column.
+    pub fn check(sources: &ConfigSources) -> Result<()> {
+        let config_file = sources.config_file();
+        let contents = fs::read_to_string(&config_file)
+            .with_context(|| format!("Failed to read {}", config_file.display()))?;
+
+        let player_found = |player: &str| crate::player::find_in_path(player).is_ok();
+        let diagnostics = check::validate(&contents, &player_found);
+        if diagnostics.is_empty() {
+            println!("{}: OK", config_file.display());
+            return Ok(());
         }
 
-        tracing::debug!("Loading config from: {}", config_file.display());
+        for diagnostic in &diagnostics {
+            println!("{}:{diagnostic}", config_file.display());
+        }
+        anyhow::bail!("Found {} problem(s) in the config file", diagnostics.len())
+    }
+}
 
-        let contents = fs::read_to_string(&config_file)?;
-        let parsed: AppConfig = toml::from_str(&contents)?;
+/// Where settings come from beyond the built-in defaults, lowest precedence first:
+/// the config file, the selected profile, `MLBV_*` environment variables, then
+/// `--set` overrides.
+#[derive(Debug, Default)]
+pub struct ConfigSources {
+    /// Alternate config file, from `--config` or `MLBV_CONFIG`.
+    pub path: Option<PathBuf>,
+    /// `[profile.<name>]` section to overlay, from `--profile` or `MLBV_PROFILE`.
+    pub profile: Option<String>,
+    /// `KEY=VALUE` pairs from `--set`.
+    pub settings: Vec<(String, String)>,
+}
 
-        Ok(parsed)
+impl ConfigSources {
+    pub fn config_file(&self) -> PathBuf {
+        self.path.clone().unwrap_or_else(config_path)
     }
 }
 
-fn parse_named_color(name: &str) -> Option<Color> {
-    match name.to_lowercase().as_str() {
-        "black" => Some(Color::FG_BLACK),
-        "red" => Some(Color::FG_RED),
-        "green" => Some(Color::FG_GREEN),
-        "yellow" => Some(Color::FG_YELLOW),
-        "blue" => Some(Color::FG_BLUE),
-        "magenta" | "purple" => Some(Color::FG_MAGENTA),
-        "cyan" => Some(Color::FG_CYAN),
-        "white" => Some(Color::FG_WHITE),
-        _ => None,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init_keeps_existing_settings() {
+        let existing = "# mine\n[credentials]\nusername = \"old\"\npassword = \"old\"\n\n[display]\nscores = false\n";
+        let updated = AppConfig::with_credentials(Some(existing), "fan", "s3cret").unwrap();
+        assert_eq!(
+            updated,
+            "# mine\n[credentials]\nusername = \"fan\"\npassword = \"s3cret\"\n\n[display]\nscores = false\n"
+        );
+
+        let fresh = AppConfig::with_credentials(None, "fan", "s3cret").unwrap();
+        assert!(fresh.starts_with(&TEMPLATE[..40]));
+        assert!(fresh.contains("password = \"s3cret\""));
+
+        assert!(AppConfig::with_credentials(None, "fan", "").is_err());
+    }
+
+    #[test]
+    fn columns_deserialize_in_configured_order() {
+        let display: Display =
+            toml::from_str(r#"columns = ["Score", "matchup", "tv"]"#).expect("valid columns");
+        assert_eq!(
+            display.columns,
+            vec![Column::Score, Column::Matchup, Column::Tv]
+        );
+        assert!(toml::from_str::<Display>(r#"columns = ["odds"]"#).is_err());
+        assert!(toml::from_str::<Display>("columns = []").is_err());
+        let err = toml::from_str::<Display>(r#"columns = ["score", "Score"]"#).unwrap_err();
+        assert!(err.message().contains("'score' is listed more than once"));
+    }
+
+    #[test]
+    fn time_format_from_str_accepts_template_values() {
+        assert_eq!(TimeFormat::from_str("12H").unwrap(), TimeFormat::Hour12);
+        assert_eq!(TimeFormat::from_str("24h").unwrap(), TimeFormat::Hour24);
+        assert!(TimeFormat::from_str("%H:%M").is_err());
+    }
+
+    #[test]
+    fn time_zone_setting_parses_keywords_and_iana_names() {
+        assert_eq!(
+            TimeZoneSetting::from_str("Ballpark").unwrap(),
+            TimeZoneSetting::Ballpark
+        );
+        assert_eq!(
+            TimeZoneSetting::from_str("America/New_York").unwrap(),
+            TimeZoneSetting::Named(chrono_tz::America::New_York)
+        );
+        assert!(TimeZoneSetting::from_str("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn favorites_pick_team_colors_over_tier_colors() {
+        let favorites: Favorites = toml::from_str(
+            r#"
+            teams = ["wsh"]
+            follow = ["tor", "nym"]
+            follow_color = "cyan"
+            [colors]
+            nym = "red"
+            "#,
+        )
+        .expect("valid favorites");
+        let id = |code: TeamCode| code.team().id;
+        let prefix = |ids| favorites.color_for(ids).map(|c| c.get_prefix().to_string());
+
+        assert_eq!(
+            prefix([id(TeamCode::Tor), id(TeamCode::Nyy)]).as_deref(),
+            Some("\u{1b}[36m")
+        );
+        assert_eq!(
+            prefix([id(TeamCode::Nyy), id(TeamCode::Nym)]).as_deref(),
+            Some("\u{1b}[31m")
+        );
+        assert_eq!(
+            prefix([id(TeamCode::Tor), id(TeamCode::Wsh)]),
+            favorites
+                .color
+                .to_tabled_color(Some(TeamCode::Wsh))
+                .map(|c| c.get_prefix().to_string())
+        );
+        assert_eq!(prefix([id(TeamCode::Nyy), id(TeamCode::Bos)]), None);
+    }
+
+    #[test]
+    fn team_critical_colors_use_a_team_in_the_game() {
+        let favorites: Favorites = toml::from_str(
+            r#"
+            teams = ["wsh"]
+            critical_color = "bg:team"
+            "#,
+        )
+        .expect("valid favorites");
+        let id = |code: TeamCode| code.team().id;
+        let prefix = |ids| {
+            favorites
+                .critical_color_for(ids)
+                .map(|c| c.get_prefix().to_string())
+        };
+        let team_prefix = |code| {
+            favorites
+                .critical_color
+                .to_tabled_color(Some(code))
+                .map(|c| c.get_prefix().to_string())
+        };
+
+        assert_eq!(
+            prefix([id(TeamCode::Wsh), id(TeamCode::Nyy)]),
+            team_prefix(TeamCode::Wsh)
+        );
+        assert_eq!(
+            prefix([id(TeamCode::Bos), id(TeamCode::Nyy)]),
+            team_prefix(TeamCode::Nyy)
+        );
+        // All-Star Game ids aren't MLB clubs.
+        assert_eq!(prefix([159, 160]), None);
+    }
+
+    #[test]
+    fn theme_defaults_to_auto_and_accepts_ascii() {
+        assert_eq!(Display::default().theme, ThemeSetting::Auto);
+        let display: Display = toml::from_str(r#"theme = "ASCII""#).expect("valid theme");
+        assert_eq!(display.theme, ThemeSetting::Ascii);
+        assert!(toml::from_str::<Display>(r#"theme = "emoji""#).is_err());
+    }
+
+    #[test]
+    fn sort_and_grouping_parse_from_display_table() {
+        let display: Display = toml::from_str(
+            r#"
+            sort = "closeness"
+            group_by = "Division"
+            favorites_first = true
+            "#,
+        )
+        .expect("valid ordering settings");
+        assert_eq!(display.sort, SortOrder::Closeness);
+        assert_eq!(display.group_by, GroupBy::Division);
+        assert!(display.favorites_first);
+        assert!(GroupBy::from_str("league").is_err());
     }
 }
@@ -0,0 +1,176 @@
+use super::config::{AppConfig, ConfigSources, TEMPLATE};
+use super::{check, overrides};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+use toml_edit::{DocumentMut, Item, TableLike};
+
+/// Keys whose values `config list` masks.
+const SECRET_KEYS: &[&str] = &["credentials.password"];
+
+fn read_document(path: &Path) -> Result<DocumentMut> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    contents
+        .parse()
+        .with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn write_document(path: &Path, doc: &DocumentMut) -> Result<()> {
+    fs::write(path, doc.to_string()).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Split `display.sort` into its parent tables and final key.
+fn split_key(key: &str) -> (Vec<&str>, &str) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let last = parts.pop().unwrap_or(key);
+    (parts, last)
+}
+
+/// Set `key` to a typed value, keeping the file's comments and any inline comment on
+/// the key. A missing config file is created from the template.
+pub(super) fn set_in_document(doc: &mut DocumentMut, key: &str, raw: &str) -> Result<()> {
+    let value = overrides::typed_value(key, raw)?;
+    let mut value: toml_edit::Value = value.to_string().parse()?;
+    let (parents, last) = split_key(key);
+
+    let mut table: &mut dyn TableLike = doc.as_table_mut();
+    for part in parents {
+        if table.get(part).is_none() {
+            // Implicit, so `profile.tv.stream` doesn't leave empty [profile] headers behind.
+            let mut new = toml_edit::Table::new();
+            new.set_implicit(true);
+            table.insert(part, Item::Table(new));
+        }
+        table = table
+            .get_mut(part)
+            .and_then(Item::as_table_like_mut)
+            .with_context(|| format!("'{part}' is not a table"))?;
+    }
+
+    if let Some(old) = table.get(last).and_then(Item::as_value) {
+        *value.decor_mut() = old.decor().clone();
+    }
+    table.insert(last, Item::Value(value));
+    Ok(())
+}
+
+/// Remove `key`, returning whether it was set.
+fn unset_in_document(doc: &mut DocumentMut, key: &str) -> bool {
+    let (parents, last) = split_key(key);
+
+    let mut table: &mut dyn TableLike = doc.as_table_mut();
+    for part in parents {
+        match table.get_mut(part).and_then(Item::as_table_like_mut) {
+            Some(inner) => table = inner,
+            None => return false,
+        }
+    }
+    table.remove(last).is_some()
+}
+
+pub fn set(sources: &ConfigSources, key: &str, raw: &str) -> Result<()> {
+    let path = sources.config_file();
+    let mut doc = match path.exists() {
+        true => read_document(&path)?,
+        false => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            TEMPLATE.parse()?
+        }
+    };
+    set_in_document(&mut doc, key, raw)?;
+    write_document(&path, &doc)
+}
+
+pub fn unset(sources: &ConfigSources, key: &str) -> Result<()> {
+    let path = sources.config_file();
+    let mut doc = read_document(&path)?;
+    if !unset_in_document(&mut doc, key) {
+        anyhow::bail!("'{key}' is not set in {}", path.display());
+    }
+    write_document(&path, &doc)
+}
+
+fn format_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Print the effective value of `key` after every layer is applied.
+pub fn get(sources: &ConfigSources, key: &str) -> Result<()> {
+    let table = AppConfig::layered_table(sources)?;
+    let (parents, last) = split_key(key);
+
+    let value = parents
+        .iter()
+        .try_fold(&table, |current, part| current.get(*part)?.as_table())
+        .and_then(|current| current.get(last));
+    match value {
+        Some(value) => {
+            println!("{}", format_value(value));
+            Ok(())
+        }
+        None if check::expect_for(key).is_some() => {
+            anyhow::bail!("'{key}' is not set; the built-in default applies")
+        }
+        None => anyhow::bail!("Unknown config key '{key}'"),
+    }
+}
+
+fn flatten(prefix: &str, table: &toml::Table, lines: &mut Vec<String>) {
+    for (key, value) in table {
+        let name = match prefix {
+            "" => key.clone(),
+            _ => format!("{prefix}.{key}"),
+        };
+        match value {
+            toml::Value::Table(inner) => flatten(&name, inner, lines),
+            toml::Value::String(s) if SECRET_KEYS.contains(&name.as_str()) && !s.is_empty() => {
+                lines.push(format!("{name} = \"********\""))
+            }
+            other => lines.push(format!("{name} = {other}")),
+        }
+    }
+}
+
+/// Print every key set by the config file, profile, environment or `--set`.
+pub fn list(sources: &ConfigSources) -> Result<()> {
+    let table = AppConfig::layered_table(sources)?;
+    let mut lines = Vec::new();
+    flatten("", &table, &mut lines);
+    for line in lines {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_preserves_comments_and_adds_missing_keys() {
+        let mut doc: DocumentMut = "# Display settings\n[display]\nscores = true # spoilers!\n"
+            .parse()
+            .unwrap();
+        set_in_document(&mut doc, "display.scores", "false").unwrap();
+        set_in_document(&mut doc, "favorites.teams", "wsh,tor").unwrap();
+
+        let text = doc.to_string();
+        assert!(text.starts_with("# Display settings\n[display]\nscores = false # spoilers!\n"));
+        assert!(text.contains("teams = [\"wsh\", \"tor\"]"));
+        assert!(set_in_document(&mut doc, "display.scores", "maybe").is_err());
+    }
+
+    #[test]
+    fn unset_removes_only_existing_keys() {
+        let mut doc: DocumentMut = "[stream]\nvideo_player = \"vlc\"\n".parse().unwrap();
+        assert!(unset_in_document(&mut doc, "stream.video_player"));
+        assert!(!unset_in_document(&mut doc, "stream.video_player"));
+        assert!(!unset_in_document(&mut doc, "display.scores"));
+    }
+}
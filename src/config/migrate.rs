@@ -0,0 +1,272 @@
+use super::check::{self, Expect};
+use super::edit;
+use anyhow::Result;
+use directories::ProjectDirs;
+use std::path::PathBuf;
+use toml_edit::{DocumentMut, TableLike};
+
+/// The config format this build writes. Files without a `version` key are version 0.
+pub const CONFIG_VERSION: i64 = 1;
+
+/// Where keys from the Python mlbv `config` file live now.
+const LEGACY_KEYS: &[(&str, &str)] = &[
+    ("username", "credentials.username"),
+    ("password", "credentials.password"),
+    ("favs", "favorites.teams"),
+    ("fav_colour", "favorites.color"),
+    ("game_critical_colour", "favorites.critical_color"),
+    ("scores", "display.scores"),
+    ("linescore", "display.linescore"),
+    ("timeformat", "display.timeformat"),
+    ("info_display_articles", "display.info_display_articles"),
+    (
+        "info_display_max_columns",
+        "display.info_display_max_columns",
+    ),
+    ("stats_limit", "display.stats_limit"),
+    ("use_short_feeds", "display.use_short_feeds"),
+    ("resolution", "stream.resolution"),
+    ("video_player", "stream.video_player"),
+    ("debug", "debug"),
+];
+
+/// An upgraded config file and what changed on the way.
+#[derive(Debug)]
+pub struct Migration {
+    /// The version the file was at, for naming its backup.
+    pub from_version: i64,
+    pub contents: String,
+    pub notes: Vec<String>,
+}
+
+/// The config file written by the Python mlbv, e.g. `~/.config/mlbv/config`.
+pub fn python_config_path() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "mlbv").map(|dirs| dirs.config_dir().join("config"))
+}
+
+/// Python mlbv configs are flat `key=value` files with `username` at the top level.
+fn is_python_config(contents: &str) -> bool {
+    let mut has_username = false;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            return false;
+        }
+        if let Some((key, _)) = line.split_once('=') {
+            has_username |= key.trim() == "username";
+        }
+    }
+    has_username
+}
+
+fn declared_version(doc: &DocumentMut) -> Option<i64> {
+    doc.get("version").and_then(|item| item.as_integer())
+}
+
+/// Upgrade `contents` to [`CONFIG_VERSION`], or `None` when it's already current.
+/// Files that don't parse are left for the loader to report. Deprecated keys are
+/// left in place for [`deprecated_notes`] to warn about.
+pub fn migrate(contents: &str) -> Result<Option<Migration>> {
+    let mut notes = Vec::new();
+
+    let (mut doc, from_version) = if is_python_config(contents) {
+        notes.push("Converted a Python mlbv config to TOML".to_string());
+        (convert_python(contents, &mut notes), 0)
+    } else {
+        let Ok(doc) = contents.parse::<DocumentMut>() else {
+            return Ok(None);
+        };
+        match declared_version(&doc) {
+            Some(CONFIG_VERSION) => return Ok(None),
+            Some(version) if version > CONFIG_VERSION => anyhow::bail!(
+                "Config version {version} is newer than this mlbv supports ({CONFIG_VERSION}); \
+                 please upgrade mlbv"
+            ),
+            version => (doc, version.unwrap_or(0)),
+        }
+    };
+    set_version(&mut doc);
+
+    Ok(Some(Migration {
+        from_version,
+        contents: doc.to_string(),
+        notes,
+    }))
+}
+
+/// A warning for each deprecated key still set in `contents`, outside of profiles.
+pub fn deprecated_notes(contents: &str) -> Vec<String> {
+    let Ok(doc) = contents.parse::<DocumentMut>() else {
+        return Vec::new();
+    };
+    check::deprecated_keys()
+        .into_iter()
+        .filter(|(key, _)| {
+            let (parents, last) = key.rsplit_once('.').unwrap_or(("", key));
+            parents
+                .split('.')
+                .filter(|p| !p.is_empty())
+                .try_fold(doc.as_table() as &dyn TableLike, |table, part| {
+                    table.get(part)?.as_table_like()
+                })
+                .is_some_and(|table| table.contains_key(last))
+        })
+        .map(|(key, reason)| format!("'{key}' is no longer used and can be removed: {reason}"))
+        .collect()
+}
+
+/// The comments at the top of the file, up to the last blank line before the first
+/// key or table. They're attached to that item, so they'd move with it.
+fn take_header(doc: &mut DocumentMut) -> Option<String> {
+    let split = |prefix: &str| {
+        let end = prefix.rfind("\n\n")?;
+        Some((prefix[..=end].to_string(), prefix[end + 1..].to_string()))
+    };
+
+    let table = doc.as_table_mut();
+    let first_key = table
+        .iter()
+        .find(|(_, item)| item.is_value())
+        .map(|(key, _)| key.to_string());
+    if let Some(first_key) = first_key {
+        let mut key = table.key_mut(&first_key)?;
+        let prefix = key.leaf_decor().prefix().and_then(|p| p.as_str())?;
+        let (header, rest) = split(prefix)?;
+        key.leaf_decor_mut().set_prefix(rest);
+        return Some(header);
+    }
+
+    let first_table = table
+        .iter_mut()
+        .filter_map(|(_, item)| item.as_table_mut())
+        .min_by_key(|table| table.position())?;
+    let prefix = first_table.decor().prefix().and_then(|p| p.as_str())?;
+    let (header, rest) = split(prefix)?;
+    first_table.decor_mut().set_prefix(rest);
+    Some(header)
+}
+
+/// Put `version` first, under the file's header comment.
+fn set_version(doc: &mut DocumentMut) {
+    doc.as_table_mut().remove("version");
+    let header = take_header(doc);
+    let table = doc.as_table_mut();
+    table.insert("version", toml_edit::value(CONFIG_VERSION));
+    if let Some(mut key) = table.key_mut("version") {
+        key.leaf_decor_mut().set_prefix(format!(
+            "{}# Config format version, used to upgrade older files. Don't change this.\n",
+            header.map(|h| format!("{h}\n")).unwrap_or_default()
+        ));
+    }
+    table.sort_values_by(|a, _, b, _| (a.get() != "version").cmp(&(b.get() != "version")));
+}
+
+fn convert_python(contents: &str, notes: &mut Vec<String>) -> DocumentMut {
+    let mut doc = DocumentMut::new();
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, raw)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let raw = raw.trim().trim_matches(['"', '\'']);
+
+        let target = match LEGACY_KEYS.iter().find(|(old, _)| *old == key) {
+            Some(&(_, new)) => new,
+            // streamlink_highlights, streamlink_passthrough and friends
+            None if key.starts_with("streamlink") => "streamlink",
+            None => {
+                notes.push(format!("Dropped '{key}': not an mlbv-rs setting"));
+                continue;
+            }
+        };
+        if let Some(Expect::Deprecated(reason)) = check::expect_for(target) {
+            notes.push(format!("Dropped '{key}': {reason}"));
+            continue;
+        }
+        if let Err(e) = edit::set_in_document(&mut doc, target, raw) {
+            notes.push(format!("Dropped '{key}': {e}"));
+        }
+    }
+    doc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::config::TEMPLATE;
+
+    fn upgrade(contents: &str) -> Migration {
+        migrate(contents).unwrap().expect("needs an upgrade")
+    }
+
+    #[test]
+    fn keeps_deprecated_keys_and_the_header() {
+        let original = "# Config file for mlbv-rs\n\n# Turn on extra debugging information\ndebug = false\n\n\
+             [credentials]\nusername = \"\"\npassword = \"\"\n\n[display]\nscores = true\nlinescore = false\n\n[streamlink]\nhighlights = false\n";
+        let migration = upgrade(original);
+
+        assert_eq!(migration.from_version, 0);
+        assert_eq!(
+            migration.contents,
+            original.replacen(
+                "\n\n",
+                "\n\n# Config format version, used to upgrade older files. \
+                 Don't change this.\nversion = 1\n\n",
+                1
+            )
+        );
+        assert!(migration.notes.is_empty());
+
+        let notes = deprecated_notes(&migration.contents);
+        assert_eq!(notes.len(), 3);
+        assert!(notes.iter().any(|n| n.contains("'debug'")));
+        assert!(notes.iter().any(|n| n.contains("'display.linescore'")));
+        assert!(deprecated_notes(TEMPLATE).is_empty());
+    }
+
+    #[test]
+    fn versions_files_without_top_level_keys() {
+        let migration = upgrade("# mine\n\n# Login\n[credentials]\nusername = \"\"\n");
+        assert_eq!(
+            migration.contents,
+            "# mine\n\n# Config format version, used to upgrade older files. Don't change this.\n\
+             version = 1\n\n# Login\n[credentials]\nusername = \"\"\n"
+        );
+    }
+
+    #[test]
+    fn converts_python_configs() {
+        let migration = upgrade(
+            "username=fan@example.com\npassword=hunter2\nfavs=wsh,tor\nfav_colour=cyan\n\
+             scores=True\nresolution=720p_alt\nvideo_player=mpv --cache 153600\n\
+             streamlink_highlights=true\nmystery=1\n",
+        );
+        let table: toml::Table = toml::from_str(&migration.contents).unwrap();
+
+        assert_eq!(table["version"].as_integer(), Some(CONFIG_VERSION));
+        assert_eq!(
+            table["credentials"]["username"].as_str(),
+            Some("fan@example.com")
+        );
+        assert_eq!(
+            table["favorites"]["teams"].as_array().map(Vec::len),
+            Some(2)
+        );
+        assert_eq!(table["display"]["scores"].as_bool(), Some(true));
+        assert_eq!(
+            table["stream"]["video_player"].as_str(),
+            Some("mpv --cache 153600")
+        );
+        assert!(!table.contains_key("streamlink"));
+        assert!(migration.notes.iter().any(|n| n.contains("'mystery'")));
+        assert!(migration.notes.iter().any(|n| n.contains("'resolution'")));
+    }
+
+    #[test]
+    fn leaves_current_files_alone_and_rejects_newer_ones() {
+        assert!(migrate(TEMPLATE).unwrap().is_none());
+        assert!(migrate("version = 99\n").is_err());
+    }
+}
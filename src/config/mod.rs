@@ -1,4 +1,12 @@
 #![allow(clippy::module_inception)]
+pub mod check;
+pub mod color;
 pub mod config;
+pub mod edit;
+pub mod migrate;
+pub mod overrides;
 
-pub use config::AppConfig;
+pub use config::{
+    AppConfig, Column, ConfigSources, Favorites, GroupBy, SortOrder, SpoilerTeam, ThemeSetting,
+    TimeFormat, TimeZoneSetting,
+};
@@ -0,0 +1,311 @@
+use super::check::{self, Expect};
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use toml::{Table, Value};
+
+/// Environment variables starting with this prefix override config keys.
+pub const ENV_PREFIX: &str = "MLBV_";
+
+/// Environment variables that aren't config keys.
+const ENV_RESERVED: &[&str] = &["MLBV_CONFIG", "MLBV_PROFILE"];
+
+/// Split a `--set` argument such as `stream.video_player=vlc` into key and value.
+pub fn parse_setting(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{s}'"))?;
+    Ok((key.trim().to_string(), value.to_string()))
+}
+
+/// Convert a raw string to the TOML type the key expects, validating it on the way.
+pub(super) fn typed_value(key: &str, raw: &str) -> Result<Value> {
+    let value = match check::expect_for(key) {
+        Some(Expect::Bool) => Value::Boolean(
+            raw.trim()
+                .to_lowercase()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("'{key}' must be true or false, got '{raw}'"))?,
+        ),
+        Some(Expect::Integer) => Value::Integer(
+            raw.trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("'{key}' must be a whole number, got '{raw}'"))?,
+        ),
+        Some(Expect::Text(validate)) => {
+            validate(raw)?;
+            Value::String(raw.to_string())
+        }
+        // Lists are comma separated, e.g. MLBV_FAVORITES_TEAMS=wsh,tor
+        Some(expect @ (Expect::TextList(validate) | Expect::TextSet(validate))) => {
+            let items: Vec<&str> = raw
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .collect();
+            for item in &items {
+                validate(item)?;
+            }
+            if let Expect::TextSet(_) = expect
+                && let Some(problem) = check::set_problem(key, &items)
+            {
+                anyhow::bail!(problem);
+            }
+            Value::Array(items.into_iter().map(|s| Value::String(s.into())).collect())
+        }
+        Some(Expect::Player) => Value::String(raw.to_string()),
+        Some(Expect::Deprecated(reason)) => {
+            anyhow::bail!("'{key}' is no longer used: {reason}")
+        }
+        Some(Expect::Table(_) | Expect::TextMap { .. } | Expect::Named(_)) | None => {
+            anyhow::bail!("Unknown config key '{key}'")
+        }
+    };
+    Ok(value)
+}
+
+fn set_key(root: &mut Table, key: &str, value: Value) -> Result<()> {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let last = parts.pop().unwrap_or(key);
+
+    let mut table = root;
+    for part in parts {
+        table = table
+            .entry(part)
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .with_context(|| format!("'{part}' is not a table"))?;
+    }
+    table.insert(last.to_string(), value);
+    Ok(())
+}
+
+/// Recursively copy `overlay` onto `base`, replacing values but merging tables.
+fn merge(base: &mut Table, overlay: &Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(key), value) {
+            (Some(Value::Table(base_table)), Value::Table(overlay_table)) => {
+                merge(base_table, overlay_table)
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Overlay the `[profile.<name>]` section onto the top-level tables.
+pub fn apply_profile(root: &mut Table, name: &str) -> Result<()> {
+    let profiles = root.get("profile").and_then(Value::as_table);
+    let Some(profile) = profiles.and_then(|p| p.get(name)).and_then(Value::as_table) else {
+        let available: Vec<&str> = profiles
+            .map(|p| p.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        match available.is_empty() {
+            true => {
+                anyhow::bail!("Unknown profile '{name}'; the config has no [profile.*] sections")
+            }
+            false => anyhow::bail!(
+                "Unknown profile '{name}'; available: {}",
+                available.join(", ")
+            ),
+        }
+    };
+    // Profiles only hold display, stream and favorites settings, as `config check` expects.
+    let mut overlay = Table::new();
+    for (key, value) in profile {
+        match (check::expect_for(&format!("profile.{name}.{key}")), value) {
+            (Some(Expect::Table(_)), Value::Table(_)) => {
+                overlay.insert(key.clone(), value.clone());
+            }
+            _ => anyhow::bail!(
+                "'profile.{name}.{key}' can't be set in a profile; \
+                 profiles only hold [favorites], [display] and [stream] settings"
+            ),
+        }
+    }
+    merge(root, &overlay);
+    Ok(())
+}
+
+/// The `MLBV_*` variables from `vars`, e.g. `env::vars_os()`. Unlike `env::vars()`,
+/// this doesn't panic on other variables that aren't valid Unicode.
+pub fn mlbv_vars(vars: impl IntoIterator<Item = (OsString, OsString)>) -> Vec<(String, String)> {
+    vars.into_iter()
+        .filter_map(|(name, value)| {
+            let name = name.into_string().ok()?;
+            if !name.starts_with(ENV_PREFIX) {
+                return None;
+            }
+            match value.into_string() {
+                Ok(value) => Some((name, value)),
+                Err(_) => {
+                    tracing::warn!("Ignoring {name}: not valid Unicode");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Apply `MLBV_*` variables, e.g. `MLBV_CREDENTIALS_USERNAME` sets `credentials.username`.
+pub fn apply_env(root: &mut Table, vars: impl IntoIterator<Item = (String, String)>) -> Result<()> {
+    for (name, raw) in vars {
+        let Some(suffix) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        if ENV_RESERVED.contains(&name.as_str()) {
+            continue;
+        }
+        let Some(key) = check::key_for_env(suffix) else {
+            tracing::warn!("Ignoring {name}: not a config key");
+            continue;
+        };
+        if let Some(Expect::Deprecated(reason)) = check::expect_for(&key) {
+            tracing::warn!("Ignoring {name}: {reason}");
+            continue;
+        }
+        let value = typed_value(&key, &raw).with_context(|| format!("Invalid {name}"))?;
+        set_key(root, &key, value)?;
+    }
+    Ok(())
+}
+
+/// Apply `--set KEY=VALUE` overrides, which take precedence over everything else.
+pub fn apply_settings(root: &mut Table, settings: &[(String, String)]) -> Result<()> {
+    for (key, raw) in settings {
+        let value = typed_value(key, raw).with_context(|| format!("Invalid --set {key}"))?;
+        set_key(root, key, value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn env_vars_map_to_typed_keys() {
+        let mut root: Table = toml::from_str("[display]\nscores = true\n").unwrap();
+        let env = vars(&[
+            ("MLBV_CREDENTIALS_PASSWORD", "123456"),
+            ("MLBV_DISPLAY_SCORES", "false"),
+            ("MLBV_FAVORITES_TEAMS", "wsh, tor"),
+            ("MLBV_CONFIG", "/etc/mlbv.toml"),
+            ("HOME", "/root"),
+        ]);
+        apply_env(&mut root, env).unwrap();
+
+        assert_eq!(
+            root["credentials"]["password"],
+            Value::String("123456".into())
+        );
+        assert_eq!(root["display"]["scores"], Value::Boolean(false));
+        assert_eq!(root["favorites"]["teams"].as_array().map(Vec::len), Some(2));
+        assert!(!root.contains_key("config"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn env_vars_that_arent_unicode_are_skipped() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let invalid = || OsString::from_vec(vec![0x66, 0x6f, 0x80]);
+        let vars = mlbv_vars([
+            (OsString::from("LS_COLORS"), invalid()),
+            (OsString::from_vec(vec![0x80]), OsString::from("x")),
+            (
+                OsString::from("MLBV_DISPLAY_SCORES"),
+                OsString::from("false"),
+            ),
+            (OsString::from("MLBV_STREAM_VIDEO_PLAYER"), invalid()),
+        ]);
+        assert_eq!(
+            vars,
+            [("MLBV_DISPLAY_SCORES".to_string(), "false".to_string())]
+        );
+    }
+
+    #[test]
+    fn profiles_overlay_only_the_keys_they_set() {
+        let mut root: Table = toml::from_str(
+            r#"
+            [stream]
+            video_player = "mpv"
+            [display]
+            scores = false
+            probable_pitchers = true
+            [profile.tv.display]
+            scores = true
+            "#,
+        )
+        .unwrap();
+        apply_profile(&mut root, "tv").unwrap();
+
+        assert_eq!(root["display"]["scores"], Value::Boolean(true));
+        assert_eq!(root["display"]["probable_pitchers"], Value::Boolean(true));
+        assert_eq!(root["stream"]["video_player"], Value::String("mpv".into()));
+
+        let err = apply_profile(&mut root, "laptop").unwrap_err();
+        assert!(err.to_string().contains("available: tv"));
+    }
+
+    #[test]
+    fn profiles_reject_keys_outside_their_sections() {
+        for profile in [
+            "[profile.tv.credentials]\npassword = \"hunter2\"\n",
+            "[profile.tv]\nversion = 2\n",
+            "[profile.tv]\ndisplay = true\n",
+        ] {
+            let mut root: Table = toml::from_str(profile).unwrap();
+            let err = apply_profile(&mut root, "tv").unwrap_err();
+            assert!(
+                err.to_string().contains("can't be set in a profile"),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn settings_reach_into_profiles() {
+        let mut root = Table::new();
+        let set = [(
+            "profile.tv.stream.video_player".to_string(),
+            "vlc".to_string(),
+        )];
+        apply_settings(&mut root, &set).unwrap();
+        assert_eq!(
+            root["profile"]["tv"]["stream"]["video_player"],
+            Value::String("vlc".into())
+        );
+
+        let bad = [(
+            "profile.tv.credentials.username".to_string(),
+            "me".to_string(),
+        )];
+        assert!(apply_settings(&mut root, &bad).is_err());
+    }
+
+    #[test]
+    fn settings_override_env_and_reject_bad_values() {
+        let mut root = Table::new();
+        apply_env(&mut root, vars(&[("MLBV_STREAM_VIDEO_PLAYER", "mpv")])).unwrap();
+        apply_settings(
+            &mut root,
+            &[("stream.video_player".to_string(), "vlc".to_string())],
+        )
+        .unwrap();
+        assert_eq!(root["stream"]["video_player"], Value::String("vlc".into()));
+
+        let bad = [("display.theme".to_string(), "neon".to_string())];
+        assert!(apply_settings(&mut root, &bad).is_err());
+        let unknown = [("display.colour".to_string(), "red".to_string())];
+        assert!(apply_settings(&mut root, &unknown).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use serde::Deserialize;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum League {
     American,
     National,
@@ -20,7 +20,7 @@ impl FromStr for League {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DivisionRegion {
     East,
     Central,
@@ -34,6 +34,16 @@ pub struct Division {
     pub league: League,
 }
 
+impl fmt::Display for Division {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let league = match self.league {
+            League::American => "AL",
+            League::National => "NL",
+        };
+        write!(f, "{league} {:?}", self.name)
+    }
+}
+
 impl FromStr for Division {
     type Err = anyhow::Error;
 
@@ -50,7 +60,7 @@ impl FromStr for Division {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
 #[serde(try_from = "String")]
 pub enum TeamCode {
     Ari,
@@ -156,33 +166,48 @@ pub struct Team {
     pub nickname: &'static str,
     pub division: &'static Division,
     pub primary_color: TeamColor,
+    pub secondary_color: TeamColor,
 }
 
-#[derive(Copy, Clone, Debug)]
-pub enum TeamColor {
-    Red,
-    Blue,
-    Orange,
-    Green,
-    Yellow,
-    Magenta,
-    Cyan,
-    // Black,
-    White,
+/// A team's official color, as 24-bit RGB.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TeamColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
 }
 
 impl TeamColor {
-    pub fn to_tabled_color(self) -> tabled::settings::Color {
-        match self {
-            TeamColor::Red => tabled::settings::Color::FG_RED,
-            TeamColor::Blue => tabled::settings::Color::FG_BLUE,
-            TeamColor::Orange => tabled::settings::Color::FG_BRIGHT_RED, // No orange in ANSI
-            TeamColor::Green => tabled::settings::Color::FG_GREEN,
-            TeamColor::Yellow => tabled::settings::Color::FG_YELLOW,
-            TeamColor::Magenta => tabled::settings::Color::FG_MAGENTA,
-            TeamColor::Cyan => tabled::settings::Color::FG_CYAN,
-            // TeamColor::Black => tabled::settings::Color::FG_BRIGHT_BLACK, // Needs to be visible
-            TeamColor::White => tabled::settings::Color::FG_WHITE,
+    pub const fn hex(rgb: u32) -> Self {
+        Self {
+            r: (rgb >> 16) as u8,
+            g: (rgb >> 8) as u8,
+            b: rgb as u8,
+        }
+    }
+
+    /// Perceived brightness from 0 to 255.
+    fn brightness(self) -> u32 {
+        (299 * self.r as u32 + 587 * self.g as u32 + 114 * self.b as u32) / 1000
+    }
+
+    /// Lightened toward white until it reads on a dark terminal background, e.g. the
+    /// navy most teams use.
+    pub fn readable(self) -> Self {
+        const MIN_BRIGHTNESS: u32 = 96;
+        let brightness = self.brightness();
+        if brightness >= MIN_BRIGHTNESS {
+            return self;
+        }
+        // Blending toward white raises brightness linearly.
+        let lift = |c: u8| {
+            let c = c as u32;
+            (c + (255 - c) * (MIN_BRIGHTNESS - brightness) / (255 - brightness)) as u8
+        };
+        Self {
+            r: lift(self.r),
+            g: lift(self.g),
+            b: lift(self.b),
         }
     }
 }
@@ -201,10 +226,12 @@ impl Team {
     }
 
     pub fn find_by_id(id: &u32) -> &'static Team {
-        TEAMS
-            .iter()
-            .find(|team| team.id == *id)
-            .expect("Team ID not found in TEAMS constant - this is a bug")
+        Self::try_find_by_id(id).expect("Team ID not found in TEAMS constant - this is a bug")
+    }
+
+    /// Like `find_by_id`, but for IDs that may belong to non-MLB clubs (e.g. minor league affiliates)
+    pub fn try_find_by_id(id: &u32) -> Option<&'static Team> {
+        TEAMS.iter().find(|team| team.id == *id)
     }
 }
 
@@ -247,7 +274,8 @@ pub const TEAMS: &[Team] = &[
         name: "Los Angeles Angels",
         nickname: "Angels",
         division: &AL_WEST,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xBA0021),
+        secondary_color: TeamColor::hex(0x003263),
     },
     Team {
         id: 109,
@@ -255,7 +283,8 @@ pub const TEAMS: &[Team] = &[
         name: "Arizona Diamondbacks",
         nickname: "Diamondbacks",
         division: &NL_WEST,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xA71930),
+        secondary_color: TeamColor::hex(0xE3D4AD),
     },
     Team {
         id: 110,
@@ -263,7 +292,8 @@ pub const TEAMS: &[Team] = &[
         name: "Baltimore Orioles",
         nickname: "Orioles",
         division: &AL_EAST,
-        primary_color: TeamColor::Orange,
+        primary_color: TeamColor::hex(0xDF4601),
+        secondary_color: TeamColor::hex(0x000000),
     },
     Team {
         id: 111,
@@ -271,7 +301,8 @@ pub const TEAMS: &[Team] = &[
         name: "Boston Red Sox",
         nickname: "Red Sox",
         division: &AL_EAST,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xBD3039),
+        secondary_color: TeamColor::hex(0x0C2340),
     },
     Team {
         id: 112,
@@ -279,7 +310,8 @@ pub const TEAMS: &[Team] = &[
         name: "Chicago Cubs",
         nickname: "Cubs",
         division: &NL_CENTRAL,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x0E3386),
+        secondary_color: TeamColor::hex(0xCC3433),
     },
     Team {
         id: 113,
@@ -287,7 +319,8 @@ pub const TEAMS: &[Team] = &[
         name: "Cincinnati Reds",
         nickname: "Reds",
         division: &NL_CENTRAL,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xC6011F),
+        secondary_color: TeamColor::hex(0x000000),
     },
     Team {
         id: 114,
@@ -295,7 +328,8 @@ pub const TEAMS: &[Team] = &[
         name: "Cleveland Guardians",
         nickname: "Guardians",
         division: &AL_CENTRAL,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0x00385D),
+        secondary_color: TeamColor::hex(0xE50022),
     },
     Team {
         id: 115,
@@ -303,7 +337,8 @@ pub const TEAMS: &[Team] = &[
         name: "Colorado Rockies",
         nickname: "Rockies",
         division: &NL_WEST,
-        primary_color: TeamColor::Magenta,
+        primary_color: TeamColor::hex(0x333366),
+        secondary_color: TeamColor::hex(0xC4CED4),
     },
     Team {
         id: 116,
@@ -311,7 +346,8 @@ pub const TEAMS: &[Team] = &[
         name: "Detroit Tigers",
         nickname: "Tigers",
         division: &AL_CENTRAL,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x0C2340),
+        secondary_color: TeamColor::hex(0xFA4616),
     },
     Team {
         id: 117,
@@ -319,7 +355,8 @@ pub const TEAMS: &[Team] = &[
         name: "Houston Astros",
         nickname: "Astros",
         division: &AL_WEST,
-        primary_color: TeamColor::Orange,
+        primary_color: TeamColor::hex(0x002D62),
+        secondary_color: TeamColor::hex(0xEB6E1F),
     },
     Team {
         id: 118,
@@ -327,7 +364,8 @@ pub const TEAMS: &[Team] = &[
         name: "Kansas City Royals",
         nickname: "Royals",
         division: &AL_CENTRAL,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x004687),
+        secondary_color: TeamColor::hex(0xBD9B60),
     },
     Team {
         id: 119,
@@ -335,7 +373,8 @@ pub const TEAMS: &[Team] = &[
         name: "Los Angeles Dodgers",
         nickname: "Dodgers",
         division: &NL_WEST,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x005A9C),
+        secondary_color: TeamColor::hex(0xEF3E42),
     },
     Team {
         id: 120,
@@ -343,7 +382,8 @@ pub const TEAMS: &[Team] = &[
         name: "Washington Nationals",
         nickname: "Nationals",
         division: &NL_EAST,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xAB0003),
+        secondary_color: TeamColor::hex(0x14225A),
     },
     Team {
         id: 121,
@@ -351,7 +391,8 @@ pub const TEAMS: &[Team] = &[
         name: "New York Mets",
         nickname: "Mets",
         division: &NL_EAST,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x002D72),
+        secondary_color: TeamColor::hex(0xFF5910),
     },
     Team {
         id: 133,
@@ -359,7 +400,8 @@ pub const TEAMS: &[Team] = &[
         name: "Athletics",   // TODO: Update/re-add city name too.
         nickname: "Athletics",
         division: &AL_WEST,
-        primary_color: TeamColor::Green,
+        primary_color: TeamColor::hex(0x003831),
+        secondary_color: TeamColor::hex(0xEFB21E),
     },
     Team {
         id: 134,
@@ -367,7 +409,8 @@ pub const TEAMS: &[Team] = &[
         name: "Pittsburgh Pirates",
         nickname: "Pirates",
         division: &NL_CENTRAL,
-        primary_color: TeamColor::Yellow,
+        primary_color: TeamColor::hex(0x27251F),
+        secondary_color: TeamColor::hex(0xFDB827),
     },
     Team {
         id: 135,
@@ -375,7 +418,8 @@ pub const TEAMS: &[Team] = &[
         name: "San Diego Padres",
         nickname: "Padres",
         division: &NL_WEST,
-        primary_color: TeamColor::Yellow,
+        primary_color: TeamColor::hex(0x2F241D),
+        secondary_color: TeamColor::hex(0xFFC425),
     },
     Team {
         id: 136,
@@ -383,7 +427,8 @@ pub const TEAMS: &[Team] = &[
         name: "Seattle Mariners",
         nickname: "Mariners",
         division: &AL_WEST,
-        primary_color: TeamColor::Cyan,
+        primary_color: TeamColor::hex(0x0C2C56),
+        secondary_color: TeamColor::hex(0x005C5C),
     },
     Team {
         id: 137,
@@ -391,7 +436,8 @@ pub const TEAMS: &[Team] = &[
         name: "San Francisco Giants",
         nickname: "Giants",
         division: &NL_WEST,
-        primary_color: TeamColor::Orange,
+        primary_color: TeamColor::hex(0xFD5A1E),
+        secondary_color: TeamColor::hex(0x27251F),
     },
     Team {
         id: 138,
@@ -399,7 +445,8 @@ pub const TEAMS: &[Team] = &[
         name: "St. Louis Cardinals",
         nickname: "Cardinals",
         division: &NL_CENTRAL,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xC41E3A),
+        secondary_color: TeamColor::hex(0x0C2340),
     },
     Team {
         id: 139,
@@ -407,7 +454,8 @@ pub const TEAMS: &[Team] = &[
         name: "Tampa Bay Rays",
         nickname: "Rays",
         division: &AL_EAST,
-        primary_color: TeamColor::Green,
+        primary_color: TeamColor::hex(0x092C5C),
+        secondary_color: TeamColor::hex(0x8FBCE6),
     },
     Team {
         id: 140,
@@ -415,7 +463,8 @@ pub const TEAMS: &[Team] = &[
         name: "Texas Rangers",
         nickname: "Rangers",
         division: &AL_WEST,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x003278),
+        secondary_color: TeamColor::hex(0xC0111F),
     },
     Team {
         id: 141,
@@ -423,7 +472,8 @@ pub const TEAMS: &[Team] = &[
         name: "Toronto Blue Jays",
         nickname: "Blue Jays",
         division: &AL_EAST,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x134A8E),
+        secondary_color: TeamColor::hex(0x1D2D5C),
     },
     Team {
         id: 142,
@@ -431,7 +481,8 @@ pub const TEAMS: &[Team] = &[
         name: "Minnesota Twins",
         nickname: "Twins",
         division: &AL_CENTRAL,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0x002B5C),
+        secondary_color: TeamColor::hex(0xD31145),
     },
     Team {
         id: 143,
@@ -439,7 +490,8 @@ pub const TEAMS: &[Team] = &[
         name: "Philadelphia Phillies",
         nickname: "Phillies",
         division: &NL_EAST,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xE81828),
+        secondary_color: TeamColor::hex(0x002D72),
     },
     Team {
         id: 144,
@@ -447,7 +499,8 @@ pub const TEAMS: &[Team] = &[
         name: "Atlanta Braves",
         nickname: "Braves",
         division: &NL_EAST,
-        primary_color: TeamColor::Red,
+        primary_color: TeamColor::hex(0xCE1141),
+        secondary_color: TeamColor::hex(0x13274F),
     },
     Team {
         id: 145,
@@ -455,7 +508,8 @@ pub const TEAMS: &[Team] = &[
         name: "Chicago White Sox",
         nickname: "White Sox",
         division: &AL_CENTRAL,
-        primary_color: TeamColor::White,
+        primary_color: TeamColor::hex(0x27251F),
+        secondary_color: TeamColor::hex(0xC4CED4),
     },
     Team {
         id: 146,
@@ -463,7 +517,8 @@ pub const TEAMS: &[Team] = &[
         name: "Miami Marlins",
         nickname: "Marlins",
         division: &NL_EAST,
-        primary_color: TeamColor::Cyan,
+        primary_color: TeamColor::hex(0x00A3E0),
+        secondary_color: TeamColor::hex(0xEF3340),
     },
     Team {
         id: 147,
@@ -471,7 +526,8 @@ pub const TEAMS: &[Team] = &[
         name: "New York Yankees",
         nickname: "Yankees",
         division: &AL_EAST,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x0C2340),
+        secondary_color: TeamColor::hex(0xC4CED3),
     },
     Team {
         id: 158,
@@ -479,6 +535,7 @@ pub const TEAMS: &[Team] = &[
         name: "Milwaukee Brewers",
         nickname: "Brewers",
         division: &NL_CENTRAL,
-        primary_color: TeamColor::Blue,
+        primary_color: TeamColor::hex(0x12284B),
+        secondary_color: TeamColor::hex(0xFFC52F),
     },
 ];
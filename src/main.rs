@@ -14,15 +14,22 @@ mod cli;
 mod config;
 mod data;
 mod player;
+mod tui;
 
 use crate::api::session::MlbSession;
 use crate::api::stats::schedule;
 use crate::cli::Cli;
 use crate::cli::args::CliMode;
-use crate::cli::display::{self, DisplayMode};
-use crate::config::AppConfig;
+use crate::cli::display::{self, Charset, DisplayMode};
+use crate::cli::output::{self, GameRecord, OutputFormat, PlaybackRecord};
+use crate::cli::spoilers::SpoilerPolicy;
+use crate::cli::{
+    article_display, calendar, completions, player_display, plays_display, roster_display,
+    standings_display, watch,
+};
+use crate::config::{AppConfig, edit};
 use crate::data::teamdata::Team;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 
 fn main() -> Result<()> {
@@ -36,9 +43,10 @@ fn main() -> Result<()> {
 
 #[tokio::main]
 async fn run() -> Result<()> {
-    let cli = Cli::parse();
-    let mode = cli.to_mode()?;
-    let display_mode = DisplayMode::from_terminal_width();
+    let mut cli = Cli::parse();
+    let display_mode = cli
+        .display_mode
+        .unwrap_or_else(DisplayMode::from_terminal_width);
 
     let log_level = match cli.verbose {
         0 => tracing::Level::WARN,
@@ -52,21 +60,236 @@ async fn run() -> Result<()> {
         .compact()
         .init();
 
-    let cfg = AppConfig::load()?;
+    if let Some(date) = cli.date {
+        cli.date = Some(date.resolve(&MlbSession::new()?).await);
+    }
+    let mut mode = cli.to_mode()?;
+
+    let sources = cli.config_sources();
+
+    // Commands that create, edit or diagnose the config can't depend on it loading.
+    match &mode {
+        CliMode::Init => {
+            let username = cli.username.as_deref();
+            return AppConfig::generate_config(
+                sources.path.as_deref(),
+                username,
+                cli.password_stdin,
+            );
+        }
+        CliMode::ConfigCheck => return AppConfig::check(&sources),
+        CliMode::ConfigSet { key, value } => return edit::set(&sources, key, value),
+        CliMode::ConfigGet { key } => return edit::get(&sources, key),
+        CliMode::ConfigUnset { key } => return edit::unset(&sources, key),
+        CliMode::ConfigList => return edit::list(&sources),
+        CliMode::Completions { shell } => {
+            completions::write_completions(*shell, &mut std::io::stdout());
+            return Ok(());
+        }
+        CliMode::ManPage => return Ok(completions::write_man_page(&mut std::io::stdout())?),
+        _ => {}
+    }
+
+    let cfg = AppConfig::load(&sources)?;
+    if let Some(filter) = mode.filter_mut() {
+        filter.resolve_favorites(&cfg.favorites.teams);
+    }
     let session = MlbSession::new()?;
     let media_player = &cfg.stream.video_player;
 
-    let scores = if cli.scores {
-        true
+    let spoilers = if cli.scores {
+        SpoilerPolicy::show_all()
     } else if cli.no_scores {
-        false
+        SpoilerPolicy::hide_all()
     } else {
-        cfg.display.scores
+        SpoilerPolicy::from_config(&cfg)
+    };
+    let schedule_options = display::ScheduleOptions {
+        display_mode,
+        spoilers,
+        probable_pitchers: cfg.display.probable_pitchers,
+        live_situation: matches!(mode, CliMode::WatchSchedule { .. }),
+        critical_inning: cfg.display.critical_inning,
+        critical_run_margin: cfg.display.critical_run_margin,
+        time_format: cfg.display.timeformat,
+        timezone: cli.tz.unwrap_or(cfg.display.timezone),
+        columns: cfg.display.columns.clone(),
+        charset: Charset::resolve(cfg.display.theme),
+        color: !display::plain_output(),
+        favorites: cfg.favorites.teams.clone(),
+        sort: cli.sort.unwrap_or(cfg.display.sort),
+        group_by: cli.group_by.unwrap_or(cfg.display.group_by),
+        favorites_first: cfg.display.favorites_first,
     };
 
     match mode {
-        CliMode::Init => {
-            AppConfig::generate_config()?;
+        // Handled before the config is loaded
+        CliMode::Init
+        | CliMode::ConfigCheck
+        | CliMode::ConfigSet { .. }
+        | CliMode::ConfigGet { .. }
+        | CliMode::ConfigUnset { .. }
+        | CliMode::ConfigList
+        | CliMode::Completions { .. }
+        | CliMode::ManPage => {}
+        CliMode::PlayerStats {
+            name,
+            season,
+            games,
+        } => {
+            let Some(player) = session.find_player(&name).await? else {
+                println!("No players found matching '{name}'");
+                return Ok(());
+            };
+            println!("{}", player_display::format_bio(&player));
+
+            for group in player.stat_groups() {
+                let stats = session
+                    .fetch_player_stats(player.id, group, season, games)
+                    .await?;
+                let tables = [
+                    player_display::create_summary_table(&stats, season, schedule_options.charset),
+                    player_display::create_game_log_table(&stats, schedule_options.charset),
+                    player_display::create_splits_table(&stats, schedule_options.charset),
+                ];
+                for table in tables.into_iter().flatten() {
+                    println!("\n{table}");
+                }
+            }
+        }
+        CliMode::Roster {
+            team_code,
+            date,
+            roster_type,
+            position_group,
+        } => {
+            let team = team_code.team();
+            let entries = session.fetch_roster(team, roster_type, date).await?;
+            if entries.is_empty() {
+                println!("No {roster_type} found for the {} on {date}", team.name);
+                return Ok(());
+            }
+
+            let tables = roster_display::create_roster_tables(
+                team,
+                roster_type,
+                &entries,
+                position_group,
+                schedule_options.charset,
+            );
+            for (idx, table) in tables.into_iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+                println!("{table}");
+            }
+        }
+        CliMode::Standings { date } => {
+            let spoilers = &schedule_options.spoilers;
+            let date = match spoilers.hides_any() {
+                true => {
+                    let schedule = session.fetch_schedule_by_date(&date, None).await?;
+                    let games = schedule.map(|s| s.games).unwrap_or_default();
+                    let standings_date = spoilers.standings_date(date, &games);
+                    if standings_date != date {
+                        println!(
+                            "Standings through {standings_date} to avoid spoilers; use --scores to include {date}."
+                        );
+                    }
+                    standings_date
+                }
+                false => date,
+            };
+
+            let standings = session.fetch_standings(date).await?;
+            if standings.is_empty() {
+                println!("No standings found for {date}");
+                return Ok(());
+            }
+
+            let favorites = schedule_options.color.then_some(&cfg.favorites);
+            let tables = standings_display::create_standings_tables(
+                standings,
+                schedule_options.charset,
+                favorites,
+            );
+            for (idx, table) in tables.into_iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+                println!("{table}");
+            }
+        }
+        CliMode::Info {
+            date,
+            team_code,
+            game_number,
+            filter,
+        } => {
+            let width = display::terminal_width().min(cfg.display.info_display_max_columns);
+            let games = if let Some(team_code) = team_code {
+                session
+                    .find_team_game(team_code.team(), date, game_number, filter.as_ref())
+                    .await?
+                    .into_iter()
+                    .collect()
+            } else if let Some(schedule) = session
+                .fetch_schedule_by_date(&date, filter.as_ref())
+                .await?
+            {
+                schedule.games
+            } else {
+                println!("No games scheduled for {date}");
+                return Ok(());
+            };
+
+            for (idx, game) in games.iter().enumerate() {
+                if idx > 0 {
+                    println!(); // Blank line between games
+                }
+                let info = article_display::format_game_info(
+                    game,
+                    cfg.display.info_display_articles,
+                    width,
+                    &schedule_options.spoilers,
+                    schedule_options.charset,
+                );
+                println!("{info}");
+            }
+        }
+        CliMode::Plays {
+            team_code,
+            date,
+            game_number,
+            filter,
+            pitches,
+        } => {
+            let team = team_code.team();
+            let Some(game) = session
+                .find_team_game(team, date, game_number, None)
+                .await?
+            else {
+                return Ok(());
+            };
+            if !schedule_options.spoilers.reveals(&game) {
+                println!("Plays hidden to avoid spoilers; use --scores to show them.");
+                return Ok(());
+            }
+
+            let plays = session.fetch_plays(game.game_pk).await?;
+            let plays = plays.filtered(filter);
+            if plays.is_empty() {
+                println!("No plays yet for the {} on {date}", team.name);
+                return Ok(());
+            }
+
+            let away = Team::find_by_id(&game.teams.away.team.id);
+            let home = Team::find_by_id(&game.teams.home.team.id);
+            let width = display::terminal_width();
+            println!(
+                "{}",
+                plays_display::format_plays(&plays, away, home, pitches, width)
+            );
         }
         CliMode::PlayStream {
             team_code,
@@ -76,13 +299,14 @@ async fn run() -> Result<()> {
             game_number,
         } => {
             let team = team_code.team();
-            if let Some(url) = session
+            if let Some((game_pk, feed, url)) = session
                 .authorize(&cfg.credentials.username, &cfg.credentials.password)
                 .await?
                 .find_stream_playback_url(team, date, media_type, feed_type, game_number)
                 .await?
             {
-                player::handle_playback_url(url, &cli, Some(media_player))?
+                let record = PlaybackRecord { game_pk, feed, url };
+                player::handle_playback_url(record, &cli, Some(media_player))?
             }
         }
         CliMode::PlayCondensedGame {
@@ -92,11 +316,13 @@ async fn run() -> Result<()> {
         } => {
             let team = team_code.team();
             let highlight_type = schedule::HighlightType::CondensedGame;
-            if let Some(url) = session
+            if let Some((game_pk, url)) = session
                 .find_highlight_playback_url(team, date, highlight_type, game_number, None)
                 .await?
             {
-                player::handle_playback_url(url, &cli, Some(media_player))?
+                let feed = highlight_type.feed_name().to_string();
+                let record = PlaybackRecord { game_pk, feed, url };
+                player::handle_playback_url(record, &cli, Some(media_player))?
             }
         }
         CliMode::PlayRecap {
@@ -111,11 +337,13 @@ async fn run() -> Result<()> {
             // If user provided a team, fetch recap for that team
             if let Some(team_code) = team_code {
                 let team = team_code.team();
-                if let Some(url) = session
+                if let Some((game_pk, url)) = session
                     .find_highlight_playback_url(team, date, highlight_type, game_number, filter)
                     .await?
                 {
-                    player::handle_playback_url(url, &cli, Some(media_player))?
+                    let feed = highlight_type.feed_name().to_string();
+                    let record = PlaybackRecord { game_pk, feed, url };
+                    player::handle_playback_url(record, &cli, Some(media_player))?
                 }
             } else if let Some(schedule) = session.fetch_schedule_by_date(&date, filter).await? {
                 // If no team provided, fetch recaps for all teams on specified day.
@@ -125,15 +353,23 @@ async fn run() -> Result<()> {
                     .map(|g| (g.teams.away.team.name, g.teams.home.team.name))
                     .collect();
 
-                println!("Found {} recap(s) for {date}:", matchups.len());
-                for (away, home) in &matchups {
-                    println!("    {} at {}", away, home);
+                // Machine-readable output collects every URL into one document.
+                let machine_output = cli.output != OutputFormat::Table;
+                let mut records = Vec::new();
+
+                if !machine_output {
+                    println!("Found {} recap(s) for {date}:", matchups.len());
+                    for (away, home) in &matchups {
+                        println!("    {} at {}", away, home);
+                    }
                 }
                 for (away, home) in matchups {
-                    println!("Playing: {} at {}", away, home);
+                    if !machine_output {
+                        println!("Playing: {} at {}", away, home);
+                    }
                     let team = Team::find_by_name(&home)
                         .ok_or_else(|| anyhow::anyhow!("Invalid team name"))?;
-                    if let Some(url) = session
+                    if let Some((game_pk, url)) = session
                         .find_highlight_playback_url(
                             team,
                             date,
@@ -143,9 +379,17 @@ async fn run() -> Result<()> {
                         )
                         .await?
                     {
-                        player::handle_playback_url(url, &cli, Some(media_player))?
+                        let feed = highlight_type.feed_name().to_string();
+                        let record = PlaybackRecord { game_pk, feed, url };
+                        match machine_output {
+                            true => records.push(record),
+                            false => player::handle_playback_url(record, &cli, Some(media_player))?,
+                        }
                     }
                 }
+                if machine_output {
+                    output::write_records(&records, cli.output)?;
+                }
             }
         }
         CliMode::RangeSchedule {
@@ -153,39 +397,91 @@ async fn run() -> Result<()> {
             end_date,
             filter,
         } => {
-            if let Some(schedules) = session
+            let schedules = session
                 .fetch_schedule_by_range(&start_date, &end_date, filter.as_ref())
-                .await?
-            {
+                .await?;
+            if cli.output != OutputFormat::Table {
+                let schedules = schedules.unwrap_or_default();
+                let records = GameRecord::from_schedules(&schedules, &schedule_options.spoilers);
+                output::write_records(&records, cli.output)?;
+            } else if let Some(schedules) = schedules {
                 for (idx, schedule) in schedules.into_iter().enumerate() {
                     if idx > 0 {
                         println!(); // Blank line between days
                     }
-                    let (rows, header_date) =
-                        display::prepare_schedule_data(schedule, &display_mode, scores);
-                    let table = display::create_schedule_table(rows, &header_date, &display_mode);
-                    let color_table = display::color_favorite_teams(table, &cfg, &display_mode);
-                    println!("{}", color_table);
+                    println!(
+                        "{}",
+                        display::format_schedule(&schedule, &cfg, &schedule_options)
+                    );
                 }
             } else {
                 println!("No games scheduled between {start_date} and {end_date}");
             }
         }
         CliMode::DaySchedule { date, filter } => {
-            if let Some(schedule) = session
+            let schedule = session
                 .fetch_schedule_by_date(&date, filter.as_ref())
-                .await?
-            {
-                let (rows, header_date) =
-                    display::prepare_schedule_data(schedule, &display_mode, scores);
-                let table = display::create_schedule_table(rows, &header_date, &display_mode);
-                let color_table = display::color_favorite_teams(table, &cfg, &display_mode);
-                println!("{}", color_table)
+                .await?;
+            if cli.output != OutputFormat::Table {
+                let schedules: Vec<_> = schedule.into_iter().collect();
+                let records = GameRecord::from_schedules(&schedules, &schedule_options.spoilers);
+                output::write_records(&records, cli.output)?;
+            } else if let Some(schedule) = schedule {
+                println!(
+                    "{}",
+                    display::format_schedule(&schedule, &cfg, &schedule_options)
+                )
             } else {
                 // TODO: Detect when in off-season and add cute "see you next spring!" message.
                 println!("No games scheduled for {date}");
             }
         }
+        CliMode::Calendar {
+            start_date,
+            end_date,
+            filter,
+            path,
+        } => {
+            let schedules = session
+                .fetch_schedule_by_range(&start_date, &end_date, filter.as_ref())
+                .await?
+                .unwrap_or_default();
+            let ics = calendar::build_calendar(&schedules, chrono::Utc::now());
+            match path {
+                Some(path) => {
+                    std::fs::write(&path, ics)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    println!(
+                        "Saved games from {start_date} to {end_date} to {}",
+                        path.display()
+                    );
+                }
+                None => print!("{ics}"),
+            }
+        }
+        CliMode::ServeCalendar { addr, days, filter } => {
+            calendar::serve_calendar(session, addr, days, filter).await?;
+        }
+        CliMode::Browse { date, filter } => {
+            tui::App::new(session, date, filter, schedule_options, &cfg)
+                .run()
+                .await?;
+        }
+        CliMode::WatchSchedule {
+            date,
+            filter,
+            interval_secs,
+        } => {
+            watch::watch_schedule(
+                &session,
+                date,
+                filter.as_ref(),
+                &schedule_options,
+                &cfg,
+                std::time::Duration::from_secs(interval_secs),
+            )
+            .await?;
+        }
     }
 
     Ok(())
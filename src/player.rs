@@ -1,9 +1,10 @@
-use crate::cli::Cli;
+use crate::cli::output::{self, OutputFormat, PlaybackRecord};
+use crate::cli::{Cli, spoilers};
 use std::io;
 use std::path::PathBuf;
 use std::process::Command;
 
-fn find_in_path(command: &str) -> anyhow::Result<PathBuf> {
+pub fn find_in_path(command: &str) -> anyhow::Result<PathBuf> {
     #[cfg(target_os = "windows")]
     let output = Command::new("where").arg(command).output()?;
 
@@ -70,15 +71,21 @@ pub fn play_stream_url(url: String, media_player: Option<&str>) -> anyhow::Resul
 }
 
 pub fn handle_playback_url(
-    url: String,
+    record: PlaybackRecord,
     cli: &Cli,
     media_player: Option<&str>,
 ) -> anyhow::Result<()> {
-    match cli.url {
-        true => {
-            println!("{url}");
+    match (cli.output, cli.url) {
+        (OutputFormat::Table, true) => {
+            println!("{}", record.url);
             Ok(())
         }
-        false => play_stream_url(url, media_player),
+        (OutputFormat::Table, false) => {
+            // Only a game that actually played counts as watched.
+            play_stream_url(record.url, media_player)?;
+            spoilers::record_watched(record.game_pk);
+            Ok(())
+        }
+        (format, _) => output::write_records(&[record], format),
     }
 }
@@ -0,0 +1,447 @@
+use crate::{
+    api::{
+        mediagateway::streams::{FeedType, MediaType},
+        session::{Authorized, MlbSession, Unauthenticated},
+        stats::schedule::{DaySchedule, GameData, HighlightType, ScheduleFilter},
+    },
+    cli::{
+        dates,
+        display::{self, GameRow, ScheduleOptions},
+        spoilers,
+    },
+    config::{AppConfig, Column},
+    player,
+};
+use anyhow::Result;
+use chrono::{Duration, Local, NaiveDate};
+use ratatui::{
+    DefaultTerminal,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    widgets::TableState,
+};
+
+pub enum MediaOption {
+    Stream { label: String, media_id: String },
+    Highlight { label: String, url: String },
+}
+
+impl MediaOption {
+    pub fn label(&self) -> &str {
+        match self {
+            MediaOption::Stream { label, .. } | MediaOption::Highlight { label, .. } => label,
+        }
+    }
+}
+
+pub enum View {
+    Schedule,
+    Game {
+        game_pk: u64,
+        options: Vec<MediaOption>,
+        state: TableState,
+    },
+}
+
+pub struct App<'a> {
+    pub date: NaiveDate,
+    pub schedule: Option<DaySchedule>,
+    pub rows: Vec<GameRow>,
+    pub header: String,
+    pub table_state: TableState,
+    pub view: View,
+    pub status: String,
+    filter: Option<ScheduleFilter>,
+    options: ScheduleOptions,
+    config: &'a AppConfig,
+    session: MlbSession<Unauthenticated>,
+    media_session: Option<MlbSession<Authorized>>,
+    should_quit: bool,
+}
+
+fn feed_label(feed_type: FeedType, media_type: MediaType) -> String {
+    let media = match media_type {
+        MediaType::Video => "TV",
+        MediaType::Audio => "Radio",
+    };
+    let feed = match feed_type {
+        FeedType::Home => "Home",
+        FeedType::Away => "Away",
+        FeedType::Network => "National",
+    };
+    format!("{media} - {feed}")
+}
+
+impl<'a> App<'a> {
+    pub fn new(
+        session: MlbSession<Unauthenticated>,
+        date: NaiveDate,
+        filter: Option<ScheduleFilter>,
+        options: ScheduleOptions,
+        config: &'a AppConfig,
+    ) -> Self {
+        Self {
+            date,
+            schedule: None,
+            rows: Vec::new(),
+            header: String::new(),
+            table_state: TableState::default(),
+            view: View::Schedule,
+            status: String::new(),
+            filter,
+            options,
+            config,
+            session,
+            media_session: None,
+            should_quit: false,
+        }
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.options.columns
+    }
+
+    /// The game behind the highlighted row, or the one opened in the game view.
+    /// Rows are sorted and grouped, so they're matched to games by `game_pk`.
+    pub fn selected_game(&self) -> Option<&GameData> {
+        let game_pk = match &self.view {
+            View::Schedule => self.rows.get(self.table_state.selected()?)?.game_pk,
+            View::Game { game_pk, .. } => *game_pk,
+        };
+        self.find_game(game_pk)
+    }
+
+    fn find_game(&self, game_pk: u64) -> Option<&GameData> {
+        let games = &self.schedule.as_ref()?.games;
+        games.iter().find(|g| g.game_pk == game_pk)
+    }
+
+    async fn load_schedule(&mut self) {
+        match self
+            .session
+            .fetch_schedule_by_date(&self.date, self.filter.as_ref())
+            .await
+        {
+            Ok(Some(schedule)) => {
+                let (rows, header) = display::prepare_schedule_data(&schedule, &self.options);
+                self.header = header;
+                self.rows = rows;
+                self.schedule = Some(schedule);
+                self.status = format!("{} game(s)", self.rows.len());
+            }
+            Ok(None) => {
+                self.header = format!("{} {}", self.date, self.date.format("%A"));
+                self.rows = Vec::new();
+                self.schedule = None;
+                self.status = format!("No games scheduled for {}", self.date);
+            }
+            Err(e) => self.status = format!("Failed to load schedule: {e:#}"),
+        }
+
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state
+            .select((!self.rows.is_empty()).then(|| selected.min(self.rows.len() - 1)));
+    }
+
+    async fn change_date(&mut self, date: NaiveDate) {
+        self.date = date;
+        self.table_state.select(Some(0));
+        self.load_schedule().await;
+    }
+
+    async fn media_session(&mut self) -> Result<&MlbSession<Authorized>> {
+        if self.media_session.is_none() {
+            let credentials = &self.config.credentials;
+            let session = MlbSession::new()?
+                .authorize(&credentials.username, &credentials.password)
+                .await?;
+            self.media_session = Some(session);
+        }
+        Ok(self.media_session.as_ref().expect("session was just set"))
+    }
+
+    /// Collect highlights (free) and, if sign-in succeeds, live/archived feeds for a game.
+    async fn open_game(&mut self, game_pk: u64) {
+        let Some(game) = self.find_game(game_pk) else {
+            return;
+        };
+
+        let mut options: Vec<MediaOption> = [
+            (HighlightType::CondensedGame, "Condensed Game"),
+            (HighlightType::Recap, "Recap"),
+        ]
+        .into_iter()
+        .filter_map(|(kind, label)| {
+            game.find_highlight(kind, "highBit")
+                .map(|url| MediaOption::Highlight {
+                    label: label.to_string(),
+                    url,
+                })
+        })
+        .collect();
+
+        let feeds = match self.media_session().await {
+            Ok(session) => session.fetch_available_feeds(&game_pk).await,
+            Err(e) => Err(e),
+        };
+        match feeds {
+            Ok(results) => {
+                let streams = results.content.into_iter().filter(|s| {
+                    s.media_state.state != "OFF" && s.language == "en" // TODO: Language preference
+                });
+                for (idx, stream) in streams.enumerate() {
+                    options.insert(
+                        idx,
+                        MediaOption::Stream {
+                            label: feed_label(stream.feed_type, stream.media_state.media_type),
+                            media_id: stream.media_id,
+                        },
+                    );
+                }
+                self.status = format!("{} option(s) available", options.len());
+            }
+            Err(e) => self.status = format!("Feeds unavailable: {e:#}"),
+        }
+
+        let mut state = TableState::default();
+        state.select((!options.is_empty()).then_some(0));
+        self.view = View::Game {
+            game_pk,
+            options,
+            state,
+        };
+    }
+
+    async fn resolve_url(&mut self, option_idx: usize) -> Result<Option<String>> {
+        let View::Game { options, .. } = &self.view else {
+            return Ok(None);
+        };
+        dates::check_archived(self.date)?;
+        match options.get(option_idx) {
+            Some(MediaOption::Highlight { url, .. }) => Ok(Some(url.clone())),
+            Some(MediaOption::Stream { media_id, .. }) => {
+                let media_id = media_id.clone();
+                let session = self.media_session().await?;
+                let playback = session.init_playback_session(&media_id).await?;
+                Ok(Some(playback.playback.url))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Hand the terminal over to the media player, then restore the UI once it exits.
+    async fn play(&mut self, terminal: &mut DefaultTerminal, option_idx: usize) -> Result<()> {
+        self.status = "Starting playback...".to_string();
+        terminal.draw(|frame| super::ui::draw(frame, self))?;
+
+        let url = match self.resolve_url(option_idx).await {
+            Ok(Some(url)) => url,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                self.status = format!("Playback failed: {e:#}");
+                return Ok(());
+            }
+        };
+
+        ratatui::restore();
+        let result = player::play_stream_url(url, Some(&self.config.stream.video_player));
+        *terminal = ratatui::init();
+
+        self.status = match result {
+            Ok(()) => {
+                self.mark_watched();
+                "Playback finished".to_string()
+            }
+            Err(e) => format!("Playback failed: {e:#}"),
+        };
+        Ok(())
+    }
+
+    /// Stop hiding the open game's result once it has played.
+    fn mark_watched(&mut self) {
+        let View::Game { game_pk, .. } = self.view else {
+            return;
+        };
+        spoilers::record_watched(game_pk);
+        self.options.spoilers.mark_watched(game_pk);
+        if let Some(schedule) = &self.schedule {
+            (self.rows, _) = display::prepare_schedule_data(schedule, &self.options);
+        }
+    }
+
+    fn move_selection(state: &mut TableState, len: usize, forward: bool) {
+        if len == 0 {
+            return;
+        }
+        let current = state.selected().unwrap_or(0);
+        let next = match forward {
+            true => (current + 1).min(len - 1),
+            false => current.saturating_sub(1),
+        };
+        state.select(Some(next));
+    }
+
+    async fn handle_schedule_key(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        code: KeyCode,
+    ) -> Result<()> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Down | KeyCode::Char('j') => {
+                Self::move_selection(&mut self.table_state, self.rows.len(), true)
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                Self::move_selection(&mut self.table_state, self.rows.len(), false)
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.change_date(self.date - Duration::days(1)).await
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.change_date(self.date + Duration::days(1)).await
+            }
+            KeyCode::Char('t') => self.change_date(Local::now().date_naive()).await,
+            KeyCode::Char('r') => self.load_schedule().await,
+            KeyCode::Enter => {
+                if let Some(game_pk) = self.selected_game().map(|g| g.game_pk) {
+                    self.status = "Loading feeds...".to_string();
+                    terminal.draw(|frame| super::ui::draw(frame, self))?;
+                    self.open_game(game_pk).await;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_game_key(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        code: KeyCode,
+    ) -> Result<()> {
+        let View::Game { options, state, .. } = &mut self.view else {
+            return Ok(());
+        };
+        match code {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.view = View::Schedule;
+                self.status.clear();
+            }
+            KeyCode::Down | KeyCode::Char('j') => Self::move_selection(state, options.len(), true),
+            KeyCode::Up | KeyCode::Char('k') => Self::move_selection(state, options.len(), false),
+            KeyCode::Enter => {
+                if let Some(idx) = state.selected() {
+                    self.play(terminal, idx).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub async fn run(mut self) -> Result<()> {
+        self.load_schedule().await;
+
+        let mut terminal = ratatui::init();
+        let result = async {
+            while !self.should_quit {
+                terminal.draw(|frame| super::ui::draw(frame, &mut self))?;
+                if let Event::Key(key) = event::read()?
+                    && key.kind == KeyEventKind::Press
+                {
+                    match self.view {
+                        View::Schedule => self.handle_schedule_key(&mut terminal, key.code).await?,
+                        View::Game { .. } => self.handle_game_key(&mut terminal, key.code).await?,
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+        ratatui::restore();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{display::Charset, spoilers::SpoilerPolicy};
+    use crate::config::{GroupBy, SortOrder, config::TEMPLATE};
+
+    fn game(game_pk: u64, start: &str, state: &str) -> GameData {
+        serde_json::from_value(serde_json::json!({
+            "gamePk": game_pk,
+            "gameDate": format!("2025-07-16T{start}:00Z"),
+            "status": {
+                "abstractGameState": state,
+                "detailedState": state,
+                "statusCode": "S",
+                "codedGameState": "S",
+            },
+            "teams": {
+                "home": { "team": { "id": 120, "name": "Washington Nationals" } },
+                "away": { "team": { "id": 141, "name": "Toronto Blue Jays" } },
+            },
+            "content": {},
+            "gameNumber": 1,
+            "gamesInSeries": 3,
+            "seriesGameNumber": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn selected_game_follows_sorted_rows() {
+        let config: AppConfig = toml::from_str(TEMPLATE).unwrap();
+        let options = ScheduleOptions {
+            display_mode: display::DisplayMode::Compact,
+            spoilers: SpoilerPolicy::show_all(),
+            probable_pitchers: false,
+            live_situation: false,
+            critical_inning: 7,
+            critical_run_margin: 2,
+            time_format: None,
+            timezone: Default::default(),
+            columns: config.display.columns.clone(),
+            charset: Charset::Ascii,
+            color: false,
+            favorites: Vec::new(),
+            sort: SortOrder::Status,
+            group_by: GroupBy::None,
+            favorites_first: false,
+        };
+        let date = NaiveDate::from_ymd_opt(2025, 7, 16).unwrap();
+        let mut app = App::new(MlbSession::new().unwrap(), date, None, options, &config);
+
+        let schedule = DaySchedule {
+            date,
+            games: vec![
+                game(1, "17:05", "Final"),
+                game(2, "19:05", "Preview"),
+                game(3, "20:10", "Live"),
+            ],
+        };
+        let (rows, _) = display::prepare_schedule_data(&schedule, &app.options);
+        app.rows = rows;
+        app.schedule = Some(schedule);
+
+        let order: Vec<u64> = app.rows.iter().map(|row| row.game_pk).collect();
+        assert_eq!(order, [3, 2, 1]);
+        for idx in 0..app.rows.len() {
+            app.table_state.select(Some(idx));
+            assert_eq!(
+                app.selected_game().map(|g| g.game_pk),
+                Some(app.rows[idx].game_pk)
+            );
+        }
+
+        app.view = View::Game {
+            game_pk: 2,
+            options: Vec::new(),
+            state: TableState::default(),
+        };
+        assert_eq!(app.selected_game().map(|g| g.game_pk), Some(2));
+    }
+}
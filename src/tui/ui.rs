@@ -0,0 +1,120 @@
+use super::app::{App, View};
+use crate::{cli::display, config::Column};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+
+const SCHEDULE_HELP: &str =
+    "Up/Down: select  Left/Right: change day  t: today  r: refresh  Enter: open  q: quit";
+const GAME_HELP: &str = "Up/Down: select  Enter: play  Esc: back  q: quit";
+
+fn row_height(cells: &[&str]) -> u16 {
+    cells
+        .iter()
+        .map(|c| c.lines().count())
+        .max()
+        .unwrap_or(1)
+        .max(1) as u16
+}
+
+fn column_width(column: Column) -> u16 {
+    match column {
+        Column::Matchup => 34,
+        Column::Series | Column::Score => 6,
+        Column::State => 12,
+        Column::Feeds => 18,
+        Column::Highlights => 10,
+        Column::Pitchers => 24,
+        Column::Venue => 22,
+        Column::Tv => 12,
+        Column::Records => 14,
+        Column::Weather => 16,
+    }
+}
+
+fn draw_schedule(frame: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let columns = app.columns();
+
+    // The first column's header carries the date, as in the printed table.
+    let header = Row::new(columns.iter().enumerate().map(|(idx, &column)| match idx {
+        0 => app.header.as_str(),
+        _ => display::column_header(column),
+    }))
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows = app.rows.iter().map(|row| {
+        let cells: Vec<&str> = columns.iter().map(|&column| row.cell(column)).collect();
+        Row::new(cells.iter().map(|&c| Cell::from(c))).height(row_height(&cells))
+    });
+
+    // The last column takes whatever width is left.
+    let widths: Vec<Constraint> = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, &column)| match idx + 1 == columns.len() {
+            true => Constraint::Min(column_width(column)),
+            false => Constraint::Length(column_width(column)),
+        })
+        .collect();
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(" mlbv "))
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    frame.render_stateful_widget(table, area, &mut app.table_state);
+}
+
+fn draw_game(frame: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let title = app
+        .selected_game()
+        .map(|g| format!(" {} at {} ", g.teams.away.team.name, g.teams.home.team.name))
+        .unwrap_or_default();
+
+    let View::Game { options, state, .. } = &mut app.view else {
+        return;
+    };
+
+    let block = Block::default().borders(Borders::ALL).title(title);
+    if options.is_empty() {
+        let empty = Paragraph::new("No feeds or highlights available for this game.").block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let rows = options.iter().map(|o| Row::new([o.label()]));
+    let table = Table::new(rows, [Constraint::Min(10)])
+        .block(block)
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    frame.render_stateful_widget(table, area, state);
+}
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [main_area, status_area, help_area] = Layout::vertical([
+        Constraint::Min(3),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let help = match app.view {
+        View::Schedule => {
+            draw_schedule(frame, app, main_area);
+            SCHEDULE_HELP
+        }
+        View::Game { .. } => {
+            draw_game(frame, app, main_area);
+            GAME_HELP
+        }
+    };
+
+    frame.render_widget(Paragraph::new(Line::from(app.status.as_str())), status_area);
+    frame.render_widget(
+        Paragraph::new(Line::from(help)).style(Style::default().add_modifier(Modifier::DIM)),
+        help_area,
+    );
+}
@@ -1,7 +1,14 @@
-use chrono::Local;
+use chrono::{Datelike, Local};
 use clap::Parser;
+use mlbv_rs::api::mediagateway::streams::MediaType;
+use mlbv_rs::api::stats::feed::PlayFilter;
+use mlbv_rs::api::stats::roster::{PositionGroup, RosterType};
 use mlbv_rs::cli::args::{Cli, CliMode};
+use mlbv_rs::cli::display::DisplayMode;
+use mlbv_rs::cli::output::OutputFormat;
+use mlbv_rs::config::{GroupBy, SortOrder, TimeZoneSetting};
 use mlbv_rs::data::teamdata::TeamCode;
+use std::path::Path;
 
 #[test]
 fn parses_init_mode() {
@@ -91,3 +98,530 @@ fn date_mutually_exclusive() {
         "Should reject mutually exclusive date args"
     );
 }
+
+#[test]
+fn parses_player_stats() {
+    let cli = Cli::parse_from(["mlbv-rs", "--player", "Juan Soto", "--date", "2024-06-01"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::PlayerStats {
+            name,
+            season,
+            games,
+        } => {
+            assert_eq!(name, "Juan Soto");
+            assert_eq!(season, 2024);
+            assert_eq!(games, 5);
+        }
+        _ => panic!("Expected PlayerStats mode"),
+    }
+}
+
+#[test]
+fn parses_roster_with_default_type() {
+    let cli = Cli::parse_from(["mlbv-rs", "--team", "sea", "--roster", "--pitchers"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Roster {
+            team_code,
+            roster_type,
+            position_group,
+            ..
+        } => {
+            assert_eq!(team_code, TeamCode::Sea);
+            assert_eq!(roster_type, RosterType::Active);
+            assert_eq!(position_group, Some(PositionGroup::Pitchers));
+        }
+        _ => panic!("Expected Roster mode"),
+    }
+}
+
+#[test]
+fn roster_requires_team() {
+    let result = Cli::try_parse_from(["mlbv-rs", "--roster", "40man"]);
+    assert!(result.is_err(), "Should reject --roster without --team");
+}
+
+#[test]
+fn parses_info_for_team() {
+    let cli = Cli::parse_from(["mlbv-rs", "--info", "--team", "chc", "--yesterday"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Info {
+            team_code: Some(team),
+            date,
+            ..
+        } => {
+            assert_eq!(team, TeamCode::Chc);
+            assert_eq!(date, Local::now().date_naive() - chrono::Duration::days(1));
+        }
+        _ => panic!("Expected Info mode with team"),
+    }
+}
+
+#[test]
+fn parses_plays_with_default_filter() {
+    let cli = Cli::parse_from(["mlbv-rs", "--team", "tor", "--plays", "-g", "2"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Plays {
+            team_code,
+            game_number,
+            filter,
+            pitches,
+            ..
+        } => {
+            assert_eq!(team_code, TeamCode::Tor);
+            assert_eq!(game_number, Some(2));
+            assert_eq!(filter, PlayFilter::Scoring);
+            assert!(!pitches);
+        }
+        _ => panic!("Expected Plays mode"),
+    }
+}
+
+#[test]
+fn parses_watch_with_default_interval() {
+    let cli = Cli::parse_from(["mlbv-rs", "--watch"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::WatchSchedule { interval_secs, .. } => assert_eq!(interval_secs, 30),
+        _ => panic!("Expected WatchSchedule mode"),
+    }
+}
+
+#[test]
+fn watch_rejects_short_intervals() {
+    let result = Cli::try_parse_from(["mlbv-rs", "--watch", "2"]);
+    assert!(result.is_err(), "Should reject intervals under 10 seconds");
+}
+
+#[test]
+fn parses_tui_mode() {
+    let cli = Cli::parse_from(["mlbv-rs", "--tui", "--date", "2025-07-04"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Browse { date, .. } => assert_eq!(date.to_string(), "2025-07-04"),
+        _ => panic!("Expected Browse mode"),
+    }
+}
+
+#[test]
+fn tui_conflicts_with_team() {
+    let result = Cli::try_parse_from(["mlbv-rs", "--tui", "--team", "nyy"]);
+    assert!(result.is_err(), "--tui should not combine with --team");
+}
+
+#[test]
+fn parses_output_format() {
+    let cli = Cli::parse_from(["mlbv-rs", "--days", "3", "--output", "csv"]);
+    assert_eq!(cli.output, OutputFormat::Csv);
+
+    let cli = Cli::parse_from(["mlbv-rs"]);
+    assert_eq!(cli.output, OutputFormat::Table);
+}
+
+#[test]
+fn output_conflicts_with_watch() {
+    let result = Cli::try_parse_from(["mlbv-rs", "--watch", "--output", "json"]);
+    assert!(result.is_err(), "--output should not combine with --watch");
+}
+
+#[test]
+fn parses_ics_export_with_default_range() {
+    let cli = Cli::parse_from(["mlbv-rs", "--ics", "--date", "2025-07-01"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Calendar {
+            start_date,
+            end_date,
+            path,
+            ..
+        } => {
+            assert_eq!(start_date.to_string(), "2025-07-01");
+            assert_eq!(end_date.to_string(), "2025-07-30");
+            assert!(path.is_none(), "No FILE should write to stdout");
+        }
+        _ => panic!("Expected Calendar mode"),
+    }
+}
+
+#[test]
+fn parses_serve_ics_with_default_address() {
+    let cli = Cli::parse_from(["mlbv-rs", "--serve-ics", "--days", "14"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::ServeCalendar { addr, days, .. } => {
+            assert_eq!(addr.to_string(), "127.0.0.1:8642");
+            assert_eq!(days, 14);
+        }
+        _ => panic!("Expected ServeCalendar mode"),
+    }
+}
+
+#[test]
+fn parses_time_zone_override() {
+    let cli = Cli::parse_from(["mlbv-rs", "--tz", "ballpark"]);
+    assert_eq!(cli.tz, Some(TimeZoneSetting::Ballpark));
+
+    let result = Cli::try_parse_from(["mlbv-rs", "--tz", "Nowhere/Special"]);
+    assert!(result.is_err(), "Unknown time zones should be rejected");
+}
+
+#[test]
+fn parses_display_mode_override() {
+    let cli = Cli::parse_from(["mlbv-rs", "--display-mode", "compact"]);
+    assert_eq!(cli.display_mode, Some(DisplayMode::Compact));
+
+    let result = Cli::try_parse_from(["mlbv-rs", "--display-mode", "wide"]);
+    assert!(result.is_err(), "Unknown display modes should be rejected");
+}
+
+#[test]
+fn parses_sort_and_group_overrides() {
+    let cli = Cli::parse_from(["mlbv-rs", "--sort", "status", "--group-by", "favorites"]);
+    assert_eq!(cli.sort, Some(SortOrder::Status));
+    assert_eq!(cli.group_by, Some(GroupBy::Favorites));
+
+    let result = Cli::try_parse_from(["mlbv-rs", "--sort", "alphabetical"]);
+    assert!(result.is_err(), "Unknown sort orders should be rejected");
+}
+
+#[test]
+fn parses_config_check_subcommand() {
+    let cli = Cli::parse_from(["mlbv-rs", "config", "check"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::ConfigCheck));
+}
+
+#[test]
+fn parses_config_overrides() {
+    let cli = Cli::parse_from([
+        "mlbv-rs",
+        "--config",
+        "/etc/mlbv.toml",
+        "--set",
+        "stream.video_player=vlc",
+        "--set",
+        "favorites.teams=wsh,tor",
+    ]);
+    let sources = cli.config_sources();
+    assert_eq!(sources.path.as_deref(), Some(Path::new("/etc/mlbv.toml")));
+    assert_eq!(
+        sources.settings[0],
+        ("stream.video_player".to_string(), "vlc".to_string())
+    );
+    assert_eq!(sources.settings.len(), 2);
+
+    let result = Cli::try_parse_from(["mlbv-rs", "--set", "display.scores"]);
+    assert!(result.is_err(), "--set needs KEY=VALUE");
+}
+
+#[test]
+fn parses_profile_selection() {
+    let cli = Cli::parse_from(["mlbv-rs", "--profile", "tv"]);
+    assert_eq!(cli.config_sources().profile.as_deref(), Some("tv"));
+}
+
+#[test]
+fn parses_config_edit_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "config", "set", "stream.video_player", "vlc"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(
+        matches!(mode, CliMode::ConfigSet { ref key, ref value } if key == "stream.video_player" && value == "vlc")
+    );
+
+    let cli = Cli::parse_from(["mlbv-rs", "config", "unset", "display.sort"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::ConfigUnset { ref key } if key == "display.sort"));
+}
+
+#[test]
+fn password_stdin_requires_init_and_username() {
+    let cli = Cli::parse_from(["mlbv-rs", "--init", "--username", "fan", "--password-stdin"]);
+    assert!(cli.password_stdin);
+    assert!(matches!(
+        cli.to_mode().expect("to_mode failed"),
+        CliMode::Init
+    ));
+
+    let result = Cli::try_parse_from(["mlbv-rs", "--init", "--password-stdin"]);
+    assert!(result.is_err(), "--password-stdin needs --username");
+}
+
+#[test]
+fn parses_play_subcommand_like_team_flag() {
+    for args in [
+        &["mlbv-rs", "play", "wsh", "--date", "2025-07-04", "--audio"][..],
+        &[
+            "mlbv-rs",
+            "--team",
+            "wsh",
+            "--date",
+            "2025-07-04",
+            "--audio",
+        ][..],
+    ] {
+        let cli = Cli::parse_from(args);
+        let mode = cli.to_mode().expect("to_mode failed");
+        match mode {
+            CliMode::PlayStream {
+                team_code,
+                date,
+                media_type,
+                ..
+            } => {
+                assert_eq!(team_code, TeamCode::Wsh);
+                assert_eq!(date.to_string(), "2025-07-04");
+                assert_eq!(media_type, MediaType::Audio);
+            }
+            _ => panic!("Expected PlayStream mode for {args:?}"),
+        }
+    }
+}
+
+#[test]
+fn parses_condensed_and_recap_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "condensed", "nym", "--yesterday"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::PlayCondensedGame {
+            team_code, date, ..
+        } => {
+            assert_eq!(team_code, TeamCode::Nym);
+            assert_eq!(date, Local::now().date_naive() - chrono::Duration::days(1));
+        }
+        _ => panic!("Expected PlayCondensedGame mode"),
+    }
+
+    let cli = Cli::parse_from(["mlbv-rs", "recap"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(
+        mode,
+        CliMode::PlayRecap {
+            team_code: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parses_schedule_subcommand() {
+    let cli = Cli::parse_from(["mlbv-rs", "schedule"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::DaySchedule { .. }));
+
+    let cli = Cli::parse_from([
+        "mlbv-rs",
+        "schedule",
+        "--date",
+        "2025-07-10",
+        "--days",
+        "-3",
+    ]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::RangeSchedule {
+            start_date,
+            end_date,
+            ..
+        } => {
+            assert_eq!(start_date.to_string(), "2025-07-07");
+            assert_eq!(end_date.to_string(), "2025-07-10");
+        }
+        _ => panic!("Expected RangeSchedule mode"),
+    }
+}
+
+#[test]
+fn parses_stats_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "player", "juan", "soto", "--games", "3"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::PlayerStats { ref name, games: 3, .. } if name == "juan soto"));
+
+    let cli = Cli::parse_from(["mlbv-rs", "roster", "sea", "40man", "--pitchers"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(
+        mode,
+        CliMode::Roster {
+            team_code: TeamCode::Sea,
+            roster_type: RosterType::FortyMan,
+            position_group: Some(PositionGroup::Pitchers),
+            ..
+        }
+    ));
+
+    let cli = Cli::parse_from(["mlbv-rs", "plays", "tor"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(
+        mode,
+        CliMode::Plays {
+            filter: PlayFilter::Scoring,
+            pitches: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parses_standings_subcommand() {
+    let cli = Cli::parse_from(["mlbv-rs", "--date", "2025-08-01", "standings"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Standings { date } => assert_eq!(date.to_string(), "2025-08-01"),
+        _ => panic!("Expected Standings mode"),
+    }
+}
+
+#[test]
+fn parses_watch_tui_and_calendar_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "watch", "60"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(
+        mode,
+        CliMode::WatchSchedule {
+            interval_secs: 60,
+            ..
+        }
+    ));
+    assert!(Cli::try_parse_from(["mlbv-rs", "watch", "5"]).is_err());
+
+    let cli = Cli::parse_from(["mlbv-rs", "tui"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::Browse { .. }));
+
+    let cli = Cli::parse_from(["mlbv-rs", "calendar", "wsh.ics", "--date", "2025-07-01"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    match mode {
+        CliMode::Calendar { end_date, path, .. } => {
+            assert_eq!(end_date.to_string(), "2025-07-30");
+            assert_eq!(path.as_deref(), Some(Path::new("wsh.ics")));
+        }
+        _ => panic!("Expected Calendar mode"),
+    }
+
+    let cli = Cli::parse_from(["mlbv-rs", "calendar", "--serve"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::ServeCalendar { .. }));
+}
+
+#[test]
+fn mode_flags_do_not_mix_with_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "--team", "nym", "play", "wsh"]);
+    assert!(
+        cli.to_mode().is_err(),
+        "--team should not combine with play"
+    );
+
+    let result = Cli::try_parse_from(["mlbv-rs", "play", "wsh", "--condensed"]);
+    assert!(result.is_err(), "--condensed isn't a play option");
+
+    let cli = Cli::parse_from(["mlbv-rs", "--yesterday", "--filter", "wsh", "schedule"]);
+    assert!(cli.to_mode().is_ok(), "Global flags go before or after");
+}
+
+#[test]
+fn output_only_applies_to_machine_readable_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "schedule", "--days", "3", "--output", "csv"]);
+    assert!(cli.to_mode().is_ok());
+
+    let cli = Cli::parse_from(["mlbv-rs", "standings", "--output", "json"]);
+    assert!(
+        cli.to_mode().is_err(),
+        "--output should not combine with standings"
+    );
+}
+
+#[test]
+fn parses_completions_and_man_subcommands() {
+    let cli = Cli::parse_from(["mlbv-rs", "completions", "fish"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::Completions { .. }));
+
+    let result = Cli::try_parse_from(["mlbv-rs", "completions", "tcsh"]);
+    assert!(result.is_err(), "Unknown shells should be rejected");
+
+    let cli = Cli::parse_from(["mlbv-rs", "man"]);
+    let mode = cli.to_mode().expect("to_mode failed");
+    assert!(matches!(mode, CliMode::ManPage));
+}
+
+#[test]
+fn parses_relative_and_named_dates() {
+    let today = Local::now().date_naive();
+    let cli = Cli::parse_from(["mlbv-rs", "play", "wsh", "--date", "-3"]);
+    match cli.to_mode().expect("to_mode failed") {
+        CliMode::PlayStream { date, .. } => assert_eq!(date, today - chrono::Duration::days(3)),
+        _ => panic!("Expected PlayStream mode"),
+    }
+
+    let cli = Cli::parse_from(["mlbv-rs", "--date", "last friday"]);
+    match cli.to_mode().expect("to_mode failed") {
+        CliMode::DaySchedule { date, .. } => {
+            assert_eq!(date.weekday(), chrono::Weekday::Fri);
+            assert!(date < today && today - date <= chrono::Duration::days(7));
+        }
+        _ => panic!("Expected DaySchedule mode"),
+    }
+}
+
+#[test]
+fn date_ranges_map_to_range_schedules() {
+    for args in [
+        &["mlbv-rs", "--date", "2024-09-01..2024-09-07"][..],
+        &["mlbv-rs", "schedule", "--date", "2024-09-01..2024-09-07"][..],
+    ] {
+        let cli = Cli::parse_from(args);
+        match cli.to_mode().expect("to_mode failed") {
+            CliMode::RangeSchedule {
+                start_date,
+                end_date,
+                ..
+            } => {
+                assert_eq!(start_date.to_string(), "2024-09-01");
+                assert_eq!(end_date.to_string(), "2024-09-07");
+            }
+            _ => panic!("Expected RangeSchedule mode for {args:?}"),
+        }
+    }
+
+    let cli = Cli::parse_from(["mlbv-rs", "play", "wsh", "--date", "2024-09-01..2024-09-07"]);
+    assert!(cli.to_mode().is_err(), "Playback needs a single day");
+
+    let cli = Cli::parse_from(["mlbv-rs", "--date", "-7..-1", "--days", "3"]);
+    assert!(cli.to_mode().is_err(), "--days can't extend a range");
+}
+
+#[test]
+fn days_count_from_the_given_date() {
+    let cli = Cli::parse_from(["mlbv-rs", "--date", "2025-07-10", "--days", "-3"]);
+    match cli.to_mode().expect("to_mode failed") {
+        CliMode::RangeSchedule {
+            start_date,
+            end_date,
+            ..
+        } => {
+            assert_eq!(start_date.to_string(), "2025-07-07");
+            assert_eq!(end_date.to_string(), "2025-07-10");
+        }
+        _ => panic!("Expected RangeSchedule mode"),
+    }
+}
+
+#[test]
+fn archive_cutoff_only_applies_to_playback() {
+    let cli = Cli::parse_from(["mlbv-rs", "--date", "2019-07-04"]);
+    assert!(matches!(
+        cli.to_mode().expect("to_mode failed"),
+        CliMode::DaySchedule { .. }
+    ));
+
+    let cli = Cli::parse_from(["mlbv-rs", "standings", "--date", "2019-07-04"]);
+    assert!(cli.to_mode().is_ok());
+
+    let cli = Cli::parse_from(["mlbv-rs", "--team", "wsh", "--date", "2019-07-04"]);
+    assert!(cli.to_mode().is_err(), "MLB.tv archives start in 2022");
+
+    let cli = Cli::parse_from(["mlbv-rs", "condensed", "wsh", "--date", "2021-09-01"]);
+    assert!(cli.to_mode().is_err(), "MLB.tv archives start in 2022");
+}